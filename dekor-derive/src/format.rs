@@ -0,0 +1,138 @@
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote};
+use syn::parse::{ParseStream, Parser};
+use syn::{Expr, LitStr, Token};
+
+use crate::{style_names, STYLES};
+
+/// Where the output of a formatting macro goes, which decides when its styles are written.
+pub(crate) enum Target {
+    /// Styled when standard output supports colors
+    Stdout,
+    /// Styled when standard error supports colors
+    Stderr,
+    /// Always styled, for arbitrary writers
+    Writer,
+}
+
+/// Expands a call to one of the `sprintln!` family into a call to the `std` macro `name`, with the
+/// styled placeholders of its format string rewritten.
+pub(crate) fn expand(input: TokenStream2, name: &str, target: Target) -> syn::Result<TokenStream2> {
+    let writer = matches!(target, Target::Writer);
+    let parser = |input: ParseStream| -> syn::Result<(Option<Expr>, Option<LitStr>, Vec<TokenTree>)> {
+        let destination = if writer {
+            let destination = input.parse::<Expr>()?;
+            input.parse::<Token![,]>()?;
+            Some(destination)
+        } else {
+            None
+        };
+        let format = if input.is_empty() { None } else { Some(input.parse::<LitStr>()?) };
+        let mut args: Vec<TokenTree> = input.parse::<TokenStream2>()?.into_iter().collect();
+        if matches!(args.last(), Some(TokenTree::Punct(p)) if p.as_char() == ',') {
+            args.pop();
+        }
+        Ok((destination, format, args))
+    };
+    let (destination, format, args) = parser.parse2(input)?;
+
+    let name = format_ident!("{}", name);
+    let destination = destination.map(|d| quote!(#d,));
+    let Some(format) = format else {
+        return Ok(quote!(::std::#name!(#destination)));
+    };
+    let (rewritten, codes) = rewrite(&format.value()).map_err(|message| syn::Error::new(format.span(), message))?;
+    if codes.is_empty() {
+        return Ok(quote!(::std::#name!(#destination #format #(#args)*)));
+    }
+
+    let when = |code: &str| match target {
+        Target::Stdout => quote!(if ::dekor::stdout_color() { #code } else { "" }),
+        Target::Stderr => quote!(if ::dekor::stderr_color() { #code } else { "" }),
+        Target::Writer => quote!(#code),
+    };
+    let styles = codes.iter().enumerate().map(|(i, code)| {
+        let ident = format_ident!("__dekor_style_{}", i);
+        let value = when(code);
+        quote!(#ident = #value)
+    });
+    let reset = when("\x1b[0m");
+    let format = LitStr::new(&rewritten, format.span());
+    Ok(quote!(::std::#name!(#destination #format #(#args)*, #(#styles,)* __dekor_reset = #reset)))
+}
+
+/// Replaces every `{arg:[Style, ...]spec}` placeholder of a format string with
+/// `{__dekor_style_N}{arg:spec}{__dekor_reset}`, returning the new string and the escape sequence
+/// of each `N`.
+fn rewrite(format: &str) -> Result<(String, Vec<String>), String> {
+    let mut out = String::with_capacity(format.len());
+    let mut codes = Vec::new();
+    let mut rest = format;
+    while let Some(i) = rest.find(['{', '}']) {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        if rest.starts_with("{{") || rest.starts_with("}}") || rest.starts_with('}') {
+            // Escaped braces, or a stray `}` left for `std` to report
+            let len = if rest.starts_with("{{") || rest.starts_with("}}") { 2 } else { 1 };
+            out.push_str(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+        let Some(end) = rest.find('}') else {
+            break;
+        };
+        let placeholder = &rest[1..end];
+        match placeholder.split_once(':') {
+            Some((arg, spec)) if spec.starts_with('[') => {
+                let close = spec.find(']').ok_or_else(|| format!("unclosed style list in `{{{}}}`", placeholder))?;
+                let code = sgr(&spec[1..close])?;
+                let spec = &spec[close + 1..];
+                out.push_str(&format!("{{__dekor_style_{}}}{{{}", codes.len(), arg));
+                if !spec.is_empty() {
+                    out.push(':');
+                    out.push_str(spec);
+                }
+                out.push_str("}{__dekor_reset}");
+                codes.push(code);
+            },
+            _ => out.push_str(&rest[..=end]),
+        }
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    Ok((out, codes))
+}
+
+/// Returns the escape sequence of a comma separated list of style names.
+fn sgr(styles: &str) -> Result<String, String> {
+    let mut params = Vec::new();
+    for style in styles.split(',').map(str::trim) {
+        match STYLES.iter().find(|(name, _)| *name == style) {
+            Some((_, code)) => params.push(code.to_string()),
+            None if style.is_empty() => return Err("empty style in style list, expected e.g. `{:[Bold, FGRed]}`".to_string()),
+            None => return Err(format!("unknown style `{}`, expected one of: {}", style, style_names())),
+        }
+    }
+    Ok(format!("\x1b[{}m", params.join(";")))
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rewrite() {
+        let (format, codes) = rewrite("{{{:[Bold, FGRed]}}} {} {name:[Italic]>8.2} {0:?}").unwrap();
+        assert_eq!(format, "{{{__dekor_style_0}{}{__dekor_reset}}} {} {__dekor_style_1}{name:>8.2}{__dekor_reset} {0:?}");
+        assert_eq!(codes, ["\x1b[1;31m", "\x1b[3m"]);
+    }
+
+    #[test]
+    fn test_rewrite_errors() {
+        assert!(rewrite("{:[Bold}").unwrap_err().starts_with("unclosed style list"));
+        assert!(rewrite("{:[Bold,]}").unwrap_err().starts_with("empty style"));
+        assert!(rewrite("{:[Blink]}").unwrap_err().starts_with("unknown style `Blink`, expected one of: Reset, Bold"));
+    }
+}
@@ -0,0 +1,393 @@
+//! # Dekor Derive
+//!
+//! The procedural macros of [dekor](https://crates.io/crates/dekor), re-exported by `dekor`:
+//! `#[derive(Styled)]` with its `derive` feature, and the `sprintln!` family of formatting macros
+//! with its `macros` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, LitInt, LitStr, Token};
+
+mod format;
+
+/// The `Style` variants accepted by name with their SGR parameter, i.e. every variant but the RGB
+/// ones, which take values.
+const STYLES: &[(&str, u8)] = &[
+    ("Reset", 0), ("Bold", 1), ("Italic", 3), ("Underline", 4),
+    ("FGBlack", 30), ("FGRed", 31), ("FGGreen", 32), ("FGYellow", 33),
+    ("FGBlue", 34), ("FGPurple", 35), ("FGCyan", 36), ("FGWhite", 37),
+    ("BGBlack", 40), ("BGRed", 41), ("BGGreen", 42), ("BGYellow", 43),
+    ("BGBlue", 44), ("BGPurple", 45), ("BGCyan", 46), ("BGWhite", 47),
+];
+
+/// Returns the names of `STYLES` as a comma separated list, for error messages.
+fn style_names() -> String {
+    STYLES.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ")
+}
+
+/// Derives a styled `Display` implementation and `dekor::Styled` for enums and structs.
+///
+/// Styles are given with `#[dekor(...)]` attributes using the names of `dekor::Style`, plus
+/// `(FGRGB, r, g, b)` and `(BGRGB, r, g, b)` for RGB colors, in any order:
+///
+/// - On an enum variant, the variant is displayed as its label in those styles. The label is the
+///   variant name, the only field of a single-field variant, or the one given by
+///   `label = "..."`.
+/// - On a struct field, the field is displayed in those styles. Fields are written in order,
+///   separated by a space or the container's `separator = "..."`, and `skip` leaves a field out.
+///   Unit structs are displayed as their label.
+/// - On the type itself, the styles are the default for variants and fields without their own.
+///
+/// Formatting flags such as width apply to the label of an enum or unit struct.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// #[derive(Styled)]
+/// #[dekor(Bold)]
+/// enum Status {
+///     #[dekor(FGGreen, label = "ok")]
+///     Passed,
+///     #[dekor(FGRed, Bold)]
+///     Failed,
+///     #[dekor((FGRGB, 255, 140, 0))]
+///     Skipped(&'static str),
+///     Pending,
+/// }
+///
+/// assert_eq!(Status::Passed.to_string(), "\x1b[32mok\x1b[0m");
+/// assert_eq!(format!("{:<8}|", Status::Failed), "\x1b[1;31mFailed  \x1b[0m|");
+/// assert_eq!(Status::Skipped("flaky").to_string(), "\x1b[38;2;255;140;0mflaky\x1b[0m");
+/// assert_eq!(Status::Pending.paint(), Paint::new().bold());
+///
+/// #[derive(Styled)]
+/// #[dekor(separator = ": ")]
+/// struct Entry {
+///     #[dekor(FGCyan)]
+///     key: String,
+///     value: u32,
+///     #[dekor(skip)]
+///     _id: usize,
+/// }
+///
+/// let entry = Entry { key: "retries".into(), value: 3, _id: 7 };
+/// assert_eq!(entry.to_string(), "\x1b[36mretries\x1b[0m: 3");
+/// ```
+///
+/// Unknown styles are rejected with the list of valid names:
+///
+/// ```compile_fail
+/// #[derive(dekor::Styled)]
+/// enum Level {
+///     #[dekor(FGOrange)] // error: unknown style `FGOrange`, expected one of: Reset, Bold, ...
+///     Warn,
+/// }
+/// ```
+///
+#[proc_macro_derive(Styled, attributes(dekor))]
+pub fn derive_styled(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Prints to standard output with a newline, like `println!`, with placeholders styled inline.
+///
+/// A placeholder whose format spec starts with a list of `dekor::Style` names in brackets, e.g.
+/// `{:[Bold, FGRed]}` or `{name:[Underline]>8}`, is wrapped in the same escape codes `style!`
+/// produces; the rest of the spec still applies to the value. The styles are only written when
+/// `dekor::stdout_color()` reports that standard output supports colors.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let (name, secs) = ("build", 3);
+/// sprintln!("{:[Bold, FGRed]} failed after {}s", name, secs);
+/// sprintln!("{name:[Underline]>8}|{{escaped}}");
+/// ```
+///
+#[proc_macro]
+pub fn sprintln(input: TokenStream) -> TokenStream {
+    format::expand(input.into(), "println", format::Target::Stdout).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Prints to standard output, like `print!`, with placeholders styled inline as with `sprintln!`.
+#[proc_macro]
+pub fn sprint(input: TokenStream) -> TokenStream {
+    format::expand(input.into(), "print", format::Target::Stdout).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Prints to standard error with a newline, like `eprintln!`, with placeholders styled inline as
+/// with `sprintln!`. The styles are only written when `dekor::stderr_color()` returns `true`.
+#[proc_macro]
+pub fn seprintln(input: TokenStream) -> TokenStream {
+    format::expand(input.into(), "eprintln", format::Target::Stderr).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Prints to standard error, like `eprint!`, with placeholders styled inline as with `sprintln!`.
+#[proc_macro]
+pub fn seprint(input: TokenStream) -> TokenStream {
+    format::expand(input.into(), "eprint", format::Target::Stderr).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Writes to a `fmt::Write` or `io::Write` destination, like `write!`, with placeholders styled
+/// inline as with `sprintln!`.
+///
+/// The destination may be anything, so the styles are always written; check
+/// `dekor::color_supported` or use a `dekor::StyledWriter` to decide beforehand.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+/// use std::fmt::Write;
+///
+/// let mut out = String::new();
+/// swrite!(out, "{:[FGGreen]} {:[Bold]>5.1}", "ok", 2.25).unwrap();
+/// assert_eq!(out, "\x1b[32mok\x1b[0m \x1b[1m  2.2\x1b[0m");
+/// ```
+///
+/// Unknown styles are compile errors:
+///
+/// ```compile_fail
+/// # use dekor::*;
+/// let mut out = String::new();
+/// // error: unknown style `Blink`, expected one of: Reset, Bold, ...
+/// swrite!(out, "{:[Blink]}", "x");
+/// ```
+///
+#[proc_macro]
+pub fn swrite(input: TokenStream) -> TokenStream {
+    format::expand(input.into(), "write", format::Target::Writer).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Writes to a `fmt::Write` or `io::Write` destination with a newline, like `writeln!`, with
+/// placeholders styled inline as with `swrite!`.
+#[proc_macro]
+pub fn swriteln(input: TokenStream) -> TokenStream {
+    format::expand(input.into(), "writeln", format::Target::Writer).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// A single item of a `#[dekor(...)]` attribute.
+enum Item {
+    Style(Ident),
+    Rgb(Ident, [LitInt; 3]),
+    Label(LitStr),
+    Separator(LitStr),
+    Skip(Span),
+}
+
+impl Parse for Item {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Paren) {
+            let content;
+            let paren = syn::parenthesized!(content in input);
+            let kind: Ident = content.parse()?;
+            if kind != "FGRGB" && kind != "BGRGB" {
+                return Err(syn::Error::new(kind.span(), "expected `(FGRGB, r, g, b)` or `(BGRGB, r, g, b)`"));
+            }
+            let mut values = Vec::new();
+            while !content.is_empty() {
+                content.parse::<Token![,]>()?;
+                if content.is_empty() {
+                    break;
+                }
+                values.push(content.parse::<LitInt>()?);
+            }
+            return match <[LitInt; 3]>::try_from(values) {
+                Ok(rgb) => Ok(Item::Rgb(kind, rgb)),
+                Err(_) => Err(syn::Error::new(paren.span.join(), format!("`{}` takes three values: `({}, r, g, b)`", kind, kind))),
+            };
+        }
+
+        let name: Ident = input.parse()?;
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            let value: LitStr = input.parse()?;
+            return match name.to_string().as_str() {
+                "label" => Ok(Item::Label(value)),
+                "separator" => Ok(Item::Separator(value)),
+                _ => Err(syn::Error::new(name.span(), format!(
+                    "unknown option `{}`, expected `label = \"...\"` or `separator = \"...\"`", name,
+                ))),
+            };
+        }
+        if name == "skip" {
+            return Ok(Item::Skip(name.span()));
+        }
+        if !STYLES.iter().any(|(style, _)| name == style) {
+            let hint = if name == "FGRGB" || name == "BGRGB" { format!(", write `({}, r, g, b)`", name) } else { String::new() };
+            return Err(syn::Error::new(name.span(), format!(
+                "unknown style `{}`{}, expected one of: {}, (FGRGB, r, g, b) or (BGRGB, r, g, b)",
+                name, hint, style_names(),
+            )));
+        }
+        Ok(Item::Style(name))
+    }
+}
+
+/// The options collected from the `#[dekor(...)]` attributes of a type, variant or field.
+#[derive(Default)]
+struct Options {
+    /// Calls on a `Paint`, or `None` without any styles
+    paint: Option<TokenStream2>,
+    label: Option<LitStr>,
+    separator: Option<LitStr>,
+    skip: Option<Span>,
+}
+
+impl Options {
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Options::default();
+        let mut calls = TokenStream2::new();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("dekor")) {
+            let items = attr.parse_args_with(Punctuated::<Item, Token![,]>::parse_terminated)?;
+            for item in items {
+                match item {
+                    Item::Style(name) => calls.extend(quote_spanned!(name.span()=> .with(::dekor::Style::#name))),
+                    Item::Rgb(kind, [r, g, b]) => {
+                        let method = if kind == "FGRGB" { quote!(fg) } else { quote!(bg) };
+                        calls.extend(quote!(.#method(::dekor::Color::Rgb(#r, #g, #b))));
+                    },
+                    Item::Label(label) => options.label = Some(label),
+                    Item::Separator(separator) => options.separator = Some(separator),
+                    Item::Skip(span) => options.skip = Some(span),
+                }
+            }
+        }
+        if !calls.is_empty() {
+            options.paint = Some(calls);
+        }
+        Ok(options)
+    }
+
+    /// Returns the paint expression, falling back to the container's styles.
+    fn paint(&self, container: &Options) -> TokenStream2 {
+        let calls = self.paint.as_ref().or(container.paint.as_ref());
+        quote!(::dekor::Paint::new()#calls)
+    }
+
+    /// Rejects the options that do not apply to the item they were given on.
+    fn reject(&self, label: bool, separator: bool, skip: bool) -> syn::Result<()> {
+        match (&self.label, &self.separator, self.skip) {
+            (Some(l), _, _) if !label => Err(syn::Error::new(l.span(), "`label` only applies to enum variants and unit structs")),
+            (_, Some(s), _) if !separator => Err(syn::Error::new(s.span(), "`separator` only applies to structs")),
+            (_, _, Some(span)) if !skip => Err(syn::Error::new(span, "`skip` only applies to struct fields")),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let container = Options::from_attrs(&input.attrs)?;
+    let name = &input.ident;
+    let mut displayed = Vec::new();
+
+    let (paint, display) = match &input.data {
+        Data::Enum(data) => {
+            container.reject(false, false, false)?;
+            let mut paints = Vec::new();
+            let mut displays = Vec::new();
+            for variant in &data.variants {
+                let options = Options::from_attrs(&variant.attrs)?;
+                options.reject(true, false, false)?;
+                for field in &variant.fields {
+                    let field_options = Options::from_attrs(&field.attrs)?;
+                    field_options.reject(false, false, false)?;
+                    if field_options.paint.is_some() {
+                        return Err(syn::Error::new(field.span(), "style the variant instead of its fields"));
+                    }
+                }
+                let ident = &variant.ident;
+                let pattern = match &variant.fields {
+                    Fields::Named(_) => quote!(Self::#ident { .. }),
+                    Fields::Unnamed(_) => quote!(Self::#ident(..)),
+                    Fields::Unit => quote!(Self::#ident),
+                };
+                let paint = options.paint(&container);
+                paints.push(quote!(#pattern => #paint));
+
+                let label = options.label.clone().unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+                displays.push(match (&variant.fields, &options.label) {
+                    (Fields::Unnamed(fields), None) if fields.unnamed.len() == 1 => {
+                        displayed.push(fields.unnamed[0].ty.clone());
+                        quote!(Self::#ident(value) => ::core::fmt::Display::fmt(&#paint.paint(value), f))
+                    },
+                    (Fields::Named(fields), None) if fields.named.len() == 1 => {
+                        let field = fields.named[0].ident.as_ref().unwrap();
+                        displayed.push(fields.named[0].ty.clone());
+                        quote!(Self::#ident { #field: value } => ::core::fmt::Display::fmt(&#paint.paint(value), f))
+                    },
+                    _ => quote!(#pattern => ::core::fmt::Display::fmt(&#paint.paint(#label), f)),
+                });
+            }
+            if paints.is_empty() {
+                (quote!(match *self {}), quote!(match *self {}))
+            } else {
+                (quote!(match self { #(#paints,)* }), quote!(match self { #(#displays,)* }))
+            }
+        },
+        Data::Struct(data) => {
+            container.reject(matches!(data.fields, Fields::Unit), true, false)?;
+            let paint = container.paint(&Options::default());
+            let display = if let Fields::Unit = data.fields {
+                let label = container.label.clone().unwrap_or_else(|| LitStr::new(&name.to_string(), name.span()));
+                quote!(::core::fmt::Display::fmt(&#paint.paint(#label), f))
+            } else {
+                let separator = container.separator.as_ref().map_or_else(|| quote!(" "), |s| quote!(#s));
+                let mut writes = Vec::new();
+                for (i, field) in data.fields.iter().enumerate() {
+                    let options = Options::from_attrs(&field.attrs)?;
+                    options.reject(false, false, true)?;
+                    if options.skip.is_some() {
+                        continue;
+                    }
+                    let member = match &field.ident {
+                        Some(ident) => quote!(#ident),
+                        None => {
+                            let index = syn::Index::from(i);
+                            quote!(#index)
+                        },
+                    };
+                    let paint = options.paint(&container);
+                    let separator = if writes.is_empty() { quote!() } else { quote!(f.write_str(#separator)?;) };
+                    displayed.push(field.ty.clone());
+                    writes.push(quote!(#separator ::core::write!(f, "{}", #paint.paint(&self.#member))?;));
+                }
+                quote!(#(#writes)* ::core::result::Result::Ok(()))
+            };
+            (paint, display)
+        },
+        Data::Union(data) => return Err(syn::Error::new(data.union_token.span(), "`Styled` cannot be derived for unions")),
+    };
+
+    let mut generics = input.generics.clone();
+    if !generics.params.is_empty() {
+        let where_clause = generics.make_where_clause();
+        for ty in &displayed {
+            where_clause.predicates.push(syn::parse_quote!(#ty: ::core::fmt::Display));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::dekor::Styled for #name #ty_generics #where_clause {
+            fn paint(&self) -> ::dekor::Paint {
+                #paint
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                #display
+            }
+        }
+    })
+}
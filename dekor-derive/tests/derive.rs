@@ -0,0 +1,33 @@
+use dekor::*;
+
+#[derive(Styled)]
+#[dekor(FGYellow)]
+struct Unit;
+
+#[derive(Styled)]
+#[dekor(Underline)]
+struct Pair<T>(#[dekor(Bold)] T, u8);
+
+#[derive(Styled)]
+enum Event<T> {
+    #[dekor(BGRed, label = "crash")]
+    Crash { _code: i32, _signal: i32 },
+    #[dekor(Italic)]
+    Log { line: T },
+    Empty(),
+}
+
+#[test]
+fn test_structs() {
+    assert_eq!(format!("{:>6}", Unit), "\x1b[33m  Unit\x1b[0m");
+    assert_eq!(Pair("a", 1).to_string(), "\x1b[1ma\x1b[0m \x1b[4m1\x1b[0m");
+    assert_eq!(Pair("a", 1).paint(), Paint::new().underline());
+}
+
+#[test]
+fn test_enum_fields_and_labels() {
+    assert_eq!(Event::<u8>::Crash { _code: 1, _signal: 9 }.to_string(), "\x1b[41mcrash\x1b[0m");
+    assert_eq!(Event::Log { line: 42 }.to_string(), "\x1b[3m42\x1b[0m");
+    assert_eq!(Event::<u8>::Empty().to_string(), "Empty");
+    assert!(Event::<u8>::Empty().paint().is_plain());
+}
@@ -0,0 +1,59 @@
+use crate::characters::{charset, Charset};
+use crate::junction::{Junction, Weight};
+
+/// The set of lines a `Table` or `Panel` is drawn with.
+///
+/// The `Slim`, `Bold`, `Double` and `Rounded` presets use box-drawing characters and fall back to
+/// `Ascii` when the global charset is set to `Charset::Ascii`. `Markdown` produces a GitHub
+/// flavored Markdown table and never applies header or row styles; panels draw it as `Ascii`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Border {
+    /// No lines, columns separated by two spaces
+    None,
+    /// `+`, `-` and `|`
+    Ascii,
+    /// Markdown table syntax
+    Markdown,
+    /// Light lines (`┌─┬─┐`)
+    #[default]
+    Slim,
+    /// Heavy lines (`┏━┳━┓`)
+    Bold,
+    /// Double lines (`╔═╦═╗`)
+    Double,
+    /// Light lines with rounded corners (`╭─┬─╮`)
+    Rounded,
+}
+
+impl Border {
+    /// Returns the character joining the given edges.
+    pub(crate) fn glyph(self, up: bool, right: bool, down: bool, left: bool) -> char {
+        let weight = match self {
+            Border::Slim | Border::Rounded => Weight::Light,
+            Border::Bold => Weight::Heavy,
+            Border::Double => Weight::Double,
+            Border::None => return ' ',
+            Border::Ascii | Border::Markdown => {
+                return match (up || down, left || right) {
+                    (true, true) => '+',
+                    (false, true) => '-',
+                    _ => '|',
+                };
+            },
+        };
+        let edge = |present: bool| if present { weight } else { Weight::None };
+        let mut junction = Junction::new(edge(up), edge(right), edge(down), edge(left));
+        if self == Border::Rounded {
+            junction = junction.arc();
+        }
+        junction.to_char().unwrap_or('+')
+    }
+
+    /// Replaces box-drawing presets with `Ascii` when the global charset is ASCII.
+    pub(crate) fn resolve(self) -> Border {
+        match (self, charset()) {
+            (Border::Slim | Border::Bold | Border::Double | Border::Rounded, Charset::Ascii) => Border::Ascii,
+            (border, _) => border,
+        }
+    }
+}
@@ -1,98 +1,1292 @@
-/// The `Utf8` enum represents a collection of UTF-8 characters.
+/// Declares the `Utf8` enum from a list of `Variant => character, "UNICODE NAME", "ascii";` entries.
 ///
-/// Each variant of this enum corresponds to a specific character.
-/// Source: <https://www.fileformat.info/info/charset/UTF-8/list.htm>
-/// NOTE: The list is currently incomplete and will be expanded on slowly
-// TODO: Complete the list
-pub enum Utf8 {
-    /// Modifier letter left arrowhead (˂)
-    ModLetterLeftArrowhead,
-    /// Modifier letter right arrowhead (˃)
-    ModLetterRightArrowhead,
-    /// Modifier letter up arrowhead (˄)
-    ModLetterUpArrowhead,
-    /// Modifier letter down arrowhead (˅)
-    ModLetterDownArrowhead,
-    /// End of guard area (—)
-    EndGuardArea,
-    /// Horizontal pipe, slim variant (—)
-    HPipeSlim, //EndGuardArea 
-    /// Vertical pipe, slim variant (│)
-    VPipeSlim, // ??? - uknown link
-    /// Joint for slim horizontal and vertical pipes (├)
-    JointPipeSlim, // ??? - unknown link
-    /// Node for slim pipes (└)
-    NodePipeSlim, // ??? - unknown link
-    /// Curved node for slim pipes (╰)
-    NodePipeCurved, // ??? - unknown link
-    /// Horizontal pipe, bold variant (━)
-    HPipeBold, // ??? - unknown link
-    /// Vertical pipe, bold variant (┃)
-    VPipeBold, // ??? - unknown link
-    /// Joint for bold horizontal and vertical pipes (┣)
-    JointPipeBold, // ??? - unknown link
-    /// Node for bold pipes (┗)
-    NodePipeBold, // ??? - unknown link
-    /// Horizontal double pipe (═)
-    HPipeDouble, // ??? - unknown link
-    /// Vertical double pipe (║)
-    VPipeDouble, // ??? - unknown link
-    /// Joint for double horizontal and vertical pipes (╠)
-    JointPipeDouble, // ??? - unknown link
-    /// Node for double pipes (╚)
-    NodePipeDouble, // ??? - unknown link
-    /// Canadian Aboriginal Syllabics letter E (ᐁ)
-    CanadianSyllabicsE,
-    /// Canadian Aboriginal Syllabics letter I (ᐃ)
-    CanadianSyllabicsI,
-    /// Canadian Aboriginal Syllabics letter O (ᐅ)
-    CanadianSyllabicsO,
-    /// Canadian Aboriginal Syllabics letter A (ᐊ)
-    CanadianSyllabicsA,
-    /// Triangle pointing downwards, represented by Canadian Syllabics E (ᐁ)
-    TriangleDown,
-    /// Triangle pointing upwards, represented by Canadian Syllabics I (ᐃ)
-    TriangleUp,
-    /// Triangle pointing to the right, represented by Canadian Syllabics O (ᐅ)
-    TriangleRight,
-    /// Triangle pointing to the left, represented by Canadian Syllabics A (ᐊ)
-    TriangleLeft,
+/// Generates the enum itself together with `Utf8::ALL`, `Utf8::as_char`, `Utf8::name` and
+/// `Utf8::ascii` so the character table only has to be written once.
+macro_rules! utf8 {
+    ($(#[$meta:meta])* pub enum $name:ident { $($(#[$doc:meta])* $variant:ident => $ch:literal, $unicode:literal, $ascii:literal;)+ }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$doc])* $variant,)+
+        }
+
+        impl $name {
+            /// Every character in the enum, in declaration order.
+            pub const ALL: &'static [$name] = &[$($name::$variant,)+];
+
+            /// Returns the `char` this variant represents.
+            pub const fn as_char(&self) -> char {
+                match self {
+                    $($name::$variant => $ch,)+
+                }
+            }
+
+            /// Returns the official Unicode name of the character this variant represents.
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $unicode,)+
+                }
+            }
+
+            /// Returns the ASCII substitute used when rendering with `Charset::Ascii`.
+            pub const fn ascii(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $ascii,)+
+                }
+            }
+        }
+    };
+}
+
+utf8! {
+    /// The `Utf8` enum represents a collection of UTF-8 characters.
+    ///
+    /// Each variant of this enum corresponds to a specific character.
+    /// Source: <https://www.fileformat.info/info/charset/UTF-8/list.htm>
+    /// NOTE: The list is currently incomplete and will be expanded on slowly
+    // TODO: Complete the list
+    pub enum Utf8 {
+        /// Modifier letter left arrowhead (˂)
+        ModLetterLeftArrowhead => '˂', "MODIFIER LETTER LEFT ARROWHEAD", "<";
+        /// Modifier letter right arrowhead (˃)
+        ModLetterRightArrowhead => '˃', "MODIFIER LETTER RIGHT ARROWHEAD", ">";
+        /// Modifier letter up arrowhead (˄)
+        ModLetterUpArrowhead => '˄', "MODIFIER LETTER UP ARROWHEAD", "^";
+        /// Modifier letter down arrowhead (˅)
+        ModLetterDownArrowhead => '˅', "MODIFIER LETTER DOWN ARROWHEAD", "v";
+        /// End of guard area (—)
+        EndGuardArea => '—', "EM DASH", "-";
+        /// Horizontal pipe, slim variant (—)
+        HPipeSlim => '—', "EM DASH", "-";
+        /// Vertical pipe, slim variant (│)
+        VPipeSlim => '│', "BOX DRAWINGS LIGHT VERTICAL", "|";
+        /// Joint for slim horizontal and vertical pipes (├)
+        JointPipeSlim => '├', "BOX DRAWINGS LIGHT VERTICAL AND RIGHT", "+";
+        /// Node for slim pipes (└)
+        NodePipeSlim => '└', "BOX DRAWINGS LIGHT UP AND RIGHT", "`";
+        /// Curved node for slim pipes (╰)
+        NodePipeCurved => '╰', "BOX DRAWINGS LIGHT ARC UP AND RIGHT", "`";
+        /// Horizontal pipe, bold variant (━)
+        HPipeBold => '━', "BOX DRAWINGS HEAVY HORIZONTAL", "-";
+        /// Vertical pipe, bold variant (┃)
+        VPipeBold => '┃', "BOX DRAWINGS HEAVY VERTICAL", "|";
+        /// Joint for bold horizontal and vertical pipes (┣)
+        JointPipeBold => '┣', "BOX DRAWINGS HEAVY VERTICAL AND RIGHT", "+";
+        /// Node for bold pipes (┗)
+        NodePipeBold => '┗', "BOX DRAWINGS HEAVY UP AND RIGHT", "`";
+        /// Horizontal double pipe (═)
+        HPipeDouble => '═', "BOX DRAWINGS DOUBLE HORIZONTAL", "=";
+        /// Vertical double pipe (║)
+        VPipeDouble => '║', "BOX DRAWINGS DOUBLE VERTICAL", "|";
+        /// Joint for double horizontal and vertical pipes (╠)
+        JointPipeDouble => '╠', "BOX DRAWINGS DOUBLE VERTICAL AND RIGHT", "+";
+        /// Node for double pipes (╚)
+        NodePipeDouble => '╚', "BOX DRAWINGS DOUBLE UP AND RIGHT", "`";
+        /// Canadian Aboriginal Syllabics letter E (ᐁ)
+        CanadianSyllabicsE => 'ᐁ', "CANADIAN SYLLABICS E", "v";
+        /// Canadian Aboriginal Syllabics letter I (ᐃ)
+        CanadianSyllabicsI => 'ᐃ', "CANADIAN SYLLABICS I", "^";
+        /// Canadian Aboriginal Syllabics letter O (ᐅ)
+        CanadianSyllabicsO => 'ᐅ', "CANADIAN SYLLABICS O", ">";
+        /// Canadian Aboriginal Syllabics letter A (ᐊ)
+        CanadianSyllabicsA => 'ᐊ', "CANADIAN SYLLABICS A", "<";
+        /// Triangle pointing downwards, represented by Canadian Syllabics E (ᐁ)
+        TriangleDown => 'ᐁ', "CANADIAN SYLLABICS E", "v";
+        /// Triangle pointing upwards, represented by Canadian Syllabics I (ᐃ)
+        TriangleUp => 'ᐃ', "CANADIAN SYLLABICS I", "^";
+        /// Triangle pointing to the right, represented by Canadian Syllabics O (ᐅ)
+        TriangleRight => 'ᐅ', "CANADIAN SYLLABICS O", ">";
+        /// Triangle pointing to the left, represented by Canadian Syllabics A (ᐊ)
+        TriangleLeft => 'ᐊ', "CANADIAN SYLLABICS A", "<";
+        // Block Elements (U+2580 - U+259F)
+        /// Upper half block (▀)
+        UpperHalfBlock => '▀', "UPPER HALF BLOCK", "\"";
+        /// Lower one eighth block (▁)
+        LowerOneEighthBlock => '▁', "LOWER ONE EIGHTH BLOCK", "_";
+        /// Lower one quarter block (▂)
+        LowerOneQuarterBlock => '▂', "LOWER ONE QUARTER BLOCK", "_";
+        /// Lower three eighths block (▃)
+        LowerThreeEighthsBlock => '▃', "LOWER THREE EIGHTHS BLOCK", "_";
+        /// Lower half block (▄)
+        LowerHalfBlock => '▄', "LOWER HALF BLOCK", "=";
+        /// Lower five eighths block (▅)
+        LowerFiveEighthsBlock => '▅', "LOWER FIVE EIGHTHS BLOCK", "=";
+        /// Lower three quarters block (▆)
+        LowerThreeQuartersBlock => '▆', "LOWER THREE QUARTERS BLOCK", "#";
+        /// Lower seven eighths block (▇)
+        LowerSevenEighthsBlock => '▇', "LOWER SEVEN EIGHTHS BLOCK", "#";
+        /// Full block (█)
+        FullBlock => '█', "FULL BLOCK", "#";
+        /// Left seven eighths block (▉)
+        LeftSevenEighthsBlock => '▉', "LEFT SEVEN EIGHTHS BLOCK", "#";
+        /// Left three quarters block (▊)
+        LeftThreeQuartersBlock => '▊', "LEFT THREE QUARTERS BLOCK", "#";
+        /// Left five eighths block (▋)
+        LeftFiveEighthsBlock => '▋', "LEFT FIVE EIGHTHS BLOCK", "=";
+        /// Left half block (▌)
+        LeftHalfBlock => '▌', "LEFT HALF BLOCK", "=";
+        /// Left three eighths block (▍)
+        LeftThreeEighthsBlock => '▍', "LEFT THREE EIGHTHS BLOCK", "-";
+        /// Left one quarter block (▎)
+        LeftOneQuarterBlock => '▎', "LEFT ONE QUARTER BLOCK", "-";
+        /// Left one eighth block (▏)
+        LeftOneEighthBlock => '▏', "LEFT ONE EIGHTH BLOCK", "-";
+        /// Right half block (▐)
+        RightHalfBlock => '▐', "RIGHT HALF BLOCK", "=";
+        /// Light shade (░)
+        LightShade => '░', "LIGHT SHADE", ".";
+        /// Medium shade (▒)
+        MediumShade => '▒', "MEDIUM SHADE", ":";
+        /// Dark shade (▓)
+        DarkShade => '▓', "DARK SHADE", "%";
+        /// Upper one eighth block (▔)
+        UpperOneEighthBlock => '▔', "UPPER ONE EIGHTH BLOCK", "\"";
+        /// Right one eighth block (▕)
+        RightOneEighthBlock => '▕', "RIGHT ONE EIGHTH BLOCK", "|";
+        /// Quadrant lower left (▖)
+        QuadrantLowerLeft => '▖', "QUADRANT LOWER LEFT", ".";
+        /// Quadrant lower right (▗)
+        QuadrantLowerRight => '▗', "QUADRANT LOWER RIGHT", ".";
+        /// Quadrant upper left (▘)
+        QuadrantUpperLeft => '▘', "QUADRANT UPPER LEFT", "'";
+        /// Quadrant upper left and lower left and lower right (▙)
+        QuadrantUpperLeftAndLowerLeftAndLowerRight => '▙', "QUADRANT UPPER LEFT AND LOWER LEFT AND LOWER RIGHT", "#";
+        /// Quadrant upper left and lower right (▚)
+        QuadrantUpperLeftAndLowerRight => '▚', "QUADRANT UPPER LEFT AND LOWER RIGHT", ":";
+        /// Quadrant upper left and upper right and lower left (▛)
+        QuadrantUpperLeftAndUpperRightAndLowerLeft => '▛', "QUADRANT UPPER LEFT AND UPPER RIGHT AND LOWER LEFT", "#";
+        /// Quadrant upper left and upper right and lower right (▜)
+        QuadrantUpperLeftAndUpperRightAndLowerRight => '▜', "QUADRANT UPPER LEFT AND UPPER RIGHT AND LOWER RIGHT", "#";
+        /// Quadrant upper right (▝)
+        QuadrantUpperRight => '▝', "QUADRANT UPPER RIGHT", "'";
+        /// Quadrant upper right and lower left (▞)
+        QuadrantUpperRightAndLowerLeft => '▞', "QUADRANT UPPER RIGHT AND LOWER LEFT", ":";
+        /// Quadrant upper right and lower left and lower right (▟)
+        QuadrantUpperRightAndLowerLeftAndLowerRight => '▟', "QUADRANT UPPER RIGHT AND LOWER LEFT AND LOWER RIGHT", "#";
+        // Geometric Shapes (U+25A0 - U+25FF)
+        /// Black square (■)
+        BlackSquare => '■', "BLACK SQUARE", "#";
+        /// White square (□)
+        WhiteSquare => '□', "WHITE SQUARE", "[]";
+        /// White square with rounded corners (▢)
+        WhiteSquareWithRoundedCorners => '▢', "WHITE SQUARE WITH ROUNDED CORNERS", "[]";
+        /// White square containing black small square (▣)
+        WhiteSquareContainingBlackSmallSquare => '▣', "WHITE SQUARE CONTAINING BLACK SMALL SQUARE", "#";
+        /// Square with horizontal fill (▤)
+        SquareWithHorizontalFill => '▤', "SQUARE WITH HORIZONTAL FILL", "[]";
+        /// Square with vertical fill (▥)
+        SquareWithVerticalFill => '▥', "SQUARE WITH VERTICAL FILL", "[]";
+        /// Square with orthogonal crosshatch fill (▦)
+        SquareWithOrthogonalCrosshatchFill => '▦', "SQUARE WITH ORTHOGONAL CROSSHATCH FILL", "[]";
+        /// Square with upper left to lower right fill (▧)
+        SquareWithUpperLeftToLowerRightFill => '▧', "SQUARE WITH UPPER LEFT TO LOWER RIGHT FILL", "[]";
+        /// Square with upper right to lower left fill (▨)
+        SquareWithUpperRightToLowerLeftFill => '▨', "SQUARE WITH UPPER RIGHT TO LOWER LEFT FILL", "[]";
+        /// Square with diagonal crosshatch fill (▩)
+        SquareWithDiagonalCrosshatchFill => '▩', "SQUARE WITH DIAGONAL CROSSHATCH FILL", "[]";
+        /// Black small square (▪)
+        BlackSmallSquare => '▪', "BLACK SMALL SQUARE", "#";
+        /// White small square (▫)
+        WhiteSmallSquare => '▫', "WHITE SMALL SQUARE", "o";
+        /// Black rectangle (▬)
+        BlackRectangle => '▬', "BLACK RECTANGLE", "#";
+        /// White rectangle (▭)
+        WhiteRectangle => '▭', "WHITE RECTANGLE", "[]";
+        /// Black vertical rectangle (▮)
+        BlackVerticalRectangle => '▮', "BLACK VERTICAL RECTANGLE", "#";
+        /// White vertical rectangle (▯)
+        WhiteVerticalRectangle => '▯', "WHITE VERTICAL RECTANGLE", "[]";
+        /// Black parallelogram (▰)
+        BlackParallelogram => '▰', "BLACK PARALLELOGRAM", "#";
+        /// White parallelogram (▱)
+        WhiteParallelogram => '▱', "WHITE PARALLELOGRAM", "[]";
+        /// Black up-pointing triangle (▲)
+        BlackUpPointingTriangle => '▲', "BLACK UP-POINTING TRIANGLE", "^";
+        /// White up-pointing triangle (△)
+        WhiteUpPointingTriangle => '△', "WHITE UP-POINTING TRIANGLE", "^";
+        /// Black up-pointing small triangle (▴)
+        BlackUpPointingSmallTriangle => '▴', "BLACK UP-POINTING SMALL TRIANGLE", "^";
+        /// White up-pointing small triangle (▵)
+        WhiteUpPointingSmallTriangle => '▵', "WHITE UP-POINTING SMALL TRIANGLE", "^";
+        /// Black right-pointing triangle (▶)
+        BlackRightPointingTriangle => '▶', "BLACK RIGHT-POINTING TRIANGLE", ">";
+        /// White right-pointing triangle (▷)
+        WhiteRightPointingTriangle => '▷', "WHITE RIGHT-POINTING TRIANGLE", ">";
+        /// Black right-pointing small triangle (▸)
+        BlackRightPointingSmallTriangle => '▸', "BLACK RIGHT-POINTING SMALL TRIANGLE", ">";
+        /// White right-pointing small triangle (▹)
+        WhiteRightPointingSmallTriangle => '▹', "WHITE RIGHT-POINTING SMALL TRIANGLE", ">";
+        /// Black right-pointing pointer (►)
+        BlackRightPointingPointer => '►', "BLACK RIGHT-POINTING POINTER", ">";
+        /// White right-pointing pointer (▻)
+        WhiteRightPointingPointer => '▻', "WHITE RIGHT-POINTING POINTER", ">";
+        /// Black down-pointing triangle (▼)
+        BlackDownPointingTriangle => '▼', "BLACK DOWN-POINTING TRIANGLE", "v";
+        /// White down-pointing triangle (▽)
+        WhiteDownPointingTriangle => '▽', "WHITE DOWN-POINTING TRIANGLE", "v";
+        /// Black down-pointing small triangle (▾)
+        BlackDownPointingSmallTriangle => '▾', "BLACK DOWN-POINTING SMALL TRIANGLE", "v";
+        /// White down-pointing small triangle (▿)
+        WhiteDownPointingSmallTriangle => '▿', "WHITE DOWN-POINTING SMALL TRIANGLE", "v";
+        /// Black left-pointing triangle (◀)
+        BlackLeftPointingTriangle => '◀', "BLACK LEFT-POINTING TRIANGLE", "<";
+        /// White left-pointing triangle (◁)
+        WhiteLeftPointingTriangle => '◁', "WHITE LEFT-POINTING TRIANGLE", "<";
+        /// Black left-pointing small triangle (◂)
+        BlackLeftPointingSmallTriangle => '◂', "BLACK LEFT-POINTING SMALL TRIANGLE", "<";
+        /// White left-pointing small triangle (◃)
+        WhiteLeftPointingSmallTriangle => '◃', "WHITE LEFT-POINTING SMALL TRIANGLE", "<";
+        /// Black left-pointing pointer (◄)
+        BlackLeftPointingPointer => '◄', "BLACK LEFT-POINTING POINTER", "<";
+        /// White left-pointing pointer (◅)
+        WhiteLeftPointingPointer => '◅', "WHITE LEFT-POINTING POINTER", "<";
+        /// Black diamond (◆)
+        BlackDiamond => '◆', "BLACK DIAMOND", "*";
+        /// White diamond (◇)
+        WhiteDiamond => '◇', "WHITE DIAMOND", "o";
+        /// White diamond containing black small diamond (◈)
+        WhiteDiamondContainingBlackSmallDiamond => '◈', "WHITE DIAMOND CONTAINING BLACK SMALL DIAMOND", "*";
+        /// Fisheye (◉)
+        Fisheye => '◉', "FISHEYE", "o";
+        /// Lozenge (◊)
+        Lozenge => '◊', "LOZENGE", "o";
+        /// White circle (○)
+        WhiteCircle => '○', "WHITE CIRCLE", "o";
+        /// Dotted circle (◌)
+        DottedCircle => '◌', "DOTTED CIRCLE", "o";
+        /// Circle with vertical fill (◍)
+        CircleWithVerticalFill => '◍', "CIRCLE WITH VERTICAL FILL", "o";
+        /// Bullseye (◎)
+        Bullseye => '◎', "BULLSEYE", "o";
+        /// Black circle (●)
+        BlackCircle => '●', "BLACK CIRCLE", "*";
+        /// Circle with left half black (◐)
+        CircleWithLeftHalfBlack => '◐', "CIRCLE WITH LEFT HALF BLACK", "*";
+        /// Circle with right half black (◑)
+        CircleWithRightHalfBlack => '◑', "CIRCLE WITH RIGHT HALF BLACK", "*";
+        /// Circle with lower half black (◒)
+        CircleWithLowerHalfBlack => '◒', "CIRCLE WITH LOWER HALF BLACK", "*";
+        /// Circle with upper half black (◓)
+        CircleWithUpperHalfBlack => '◓', "CIRCLE WITH UPPER HALF BLACK", "*";
+        /// Circle with upper right quadrant black (◔)
+        CircleWithUpperRightQuadrantBlack => '◔', "CIRCLE WITH UPPER RIGHT QUADRANT BLACK", "*";
+        /// Circle with all but upper left quadrant black (◕)
+        CircleWithAllButUpperLeftQuadrantBlack => '◕', "CIRCLE WITH ALL BUT UPPER LEFT QUADRANT BLACK", "*";
+        /// Left half black circle (◖)
+        LeftHalfBlackCircle => '◖', "LEFT HALF BLACK CIRCLE", "*";
+        /// Right half black circle (◗)
+        RightHalfBlackCircle => '◗', "RIGHT HALF BLACK CIRCLE", "*";
+        /// Inverse bullet (◘)
+        InverseBullet => '◘', "INVERSE BULLET", "o";
+        /// Inverse white circle (◙)
+        InverseWhiteCircle => '◙', "INVERSE WHITE CIRCLE", "o";
+        /// Upper half inverse white circle (◚)
+        UpperHalfInverseWhiteCircle => '◚', "UPPER HALF INVERSE WHITE CIRCLE", "o";
+        /// Lower half inverse white circle (◛)
+        LowerHalfInverseWhiteCircle => '◛', "LOWER HALF INVERSE WHITE CIRCLE", "o";
+        /// Upper left quadrant circular arc (◜)
+        UpperLeftQuadrantCircularArc => '◜', "UPPER LEFT QUADRANT CIRCULAR ARC", "(";
+        /// Upper right quadrant circular arc (◝)
+        UpperRightQuadrantCircularArc => '◝', "UPPER RIGHT QUADRANT CIRCULAR ARC", ")";
+        /// Lower right quadrant circular arc (◞)
+        LowerRightQuadrantCircularArc => '◞', "LOWER RIGHT QUADRANT CIRCULAR ARC", ")";
+        /// Lower left quadrant circular arc (◟)
+        LowerLeftQuadrantCircularArc => '◟', "LOWER LEFT QUADRANT CIRCULAR ARC", "(";
+        /// Upper half circle (◠)
+        UpperHalfCircle => '◠', "UPPER HALF CIRCLE", "o";
+        /// Lower half circle (◡)
+        LowerHalfCircle => '◡', "LOWER HALF CIRCLE", "o";
+        /// Black lower right triangle (◢)
+        BlackLowerRightTriangle => '◢', "BLACK LOWER RIGHT TRIANGLE", "v";
+        /// Black lower left triangle (◣)
+        BlackLowerLeftTriangle => '◣', "BLACK LOWER LEFT TRIANGLE", "v";
+        /// Black upper left triangle (◤)
+        BlackUpperLeftTriangle => '◤', "BLACK UPPER LEFT TRIANGLE", "^";
+        /// Black upper right triangle (◥)
+        BlackUpperRightTriangle => '◥', "BLACK UPPER RIGHT TRIANGLE", "^";
+        /// White bullet (◦)
+        WhiteBullet => '◦', "WHITE BULLET", "o";
+        /// Square with left half black (◧)
+        SquareWithLeftHalfBlack => '◧', "SQUARE WITH LEFT HALF BLACK", "#";
+        /// Square with right half black (◨)
+        SquareWithRightHalfBlack => '◨', "SQUARE WITH RIGHT HALF BLACK", "#";
+        /// Square with upper left diagonal half black (◩)
+        SquareWithUpperLeftDiagonalHalfBlack => '◩', "SQUARE WITH UPPER LEFT DIAGONAL HALF BLACK", "#";
+        /// Square with lower right diagonal half black (◪)
+        SquareWithLowerRightDiagonalHalfBlack => '◪', "SQUARE WITH LOWER RIGHT DIAGONAL HALF BLACK", "#";
+        /// White square with vertical bisecting line (◫)
+        WhiteSquareWithVerticalBisectingLine => '◫', "WHITE SQUARE WITH VERTICAL BISECTING LINE", "[]";
+        /// White up-pointing triangle with dot (◬)
+        WhiteUpPointingTriangleWithDot => '◬', "WHITE UP-POINTING TRIANGLE WITH DOT", "^";
+        /// Up-pointing triangle with left half black (◭)
+        UpPointingTriangleWithLeftHalfBlack => '◭', "UP-POINTING TRIANGLE WITH LEFT HALF BLACK", "^";
+        /// Up-pointing triangle with right half black (◮)
+        UpPointingTriangleWithRightHalfBlack => '◮', "UP-POINTING TRIANGLE WITH RIGHT HALF BLACK", "^";
+        /// Large circle (◯)
+        LargeCircle => '◯', "LARGE CIRCLE", "O";
+        /// White square with upper left quadrant (◰)
+        WhiteSquareWithUpperLeftQuadrant => '◰', "WHITE SQUARE WITH UPPER LEFT QUADRANT", "[]";
+        /// White square with lower left quadrant (◱)
+        WhiteSquareWithLowerLeftQuadrant => '◱', "WHITE SQUARE WITH LOWER LEFT QUADRANT", "[]";
+        /// White square with lower right quadrant (◲)
+        WhiteSquareWithLowerRightQuadrant => '◲', "WHITE SQUARE WITH LOWER RIGHT QUADRANT", "[]";
+        /// White square with upper right quadrant (◳)
+        WhiteSquareWithUpperRightQuadrant => '◳', "WHITE SQUARE WITH UPPER RIGHT QUADRANT", "[]";
+        /// White circle with upper left quadrant (◴)
+        WhiteCircleWithUpperLeftQuadrant => '◴', "WHITE CIRCLE WITH UPPER LEFT QUADRANT", "o";
+        /// White circle with lower left quadrant (◵)
+        WhiteCircleWithLowerLeftQuadrant => '◵', "WHITE CIRCLE WITH LOWER LEFT QUADRANT", "o";
+        /// White circle with lower right quadrant (◶)
+        WhiteCircleWithLowerRightQuadrant => '◶', "WHITE CIRCLE WITH LOWER RIGHT QUADRANT", "o";
+        /// White circle with upper right quadrant (◷)
+        WhiteCircleWithUpperRightQuadrant => '◷', "WHITE CIRCLE WITH UPPER RIGHT QUADRANT", "o";
+        /// Upper left triangle (◸)
+        UpperLeftTriangle => '◸', "UPPER LEFT TRIANGLE", "^";
+        /// Upper right triangle (◹)
+        UpperRightTriangle => '◹', "UPPER RIGHT TRIANGLE", "^";
+        /// Lower left triangle (◺)
+        LowerLeftTriangle => '◺', "LOWER LEFT TRIANGLE", "v";
+        /// White medium square (◻)
+        WhiteMediumSquare => '◻', "WHITE MEDIUM SQUARE", "[]";
+        /// Black medium square (◼)
+        BlackMediumSquare => '◼', "BLACK MEDIUM SQUARE", "#";
+        /// White medium small square (◽)
+        WhiteMediumSmallSquare => '◽', "WHITE MEDIUM SMALL SQUARE", "o";
+        /// Black medium small square (◾)
+        BlackMediumSmallSquare => '◾', "BLACK MEDIUM SMALL SQUARE", "#";
+        /// Lower right triangle (◿)
+        LowerRightTriangle => '◿', "LOWER RIGHT TRIANGLE", "v";
+        // General Punctuation bullets and ellipses
+        /// Bullet (•)
+        Bullet => '•', "BULLET", "*";
+        /// Triangular bullet (‣)
+        TriangularBullet => '‣', "TRIANGULAR BULLET", ">";
+        /// One dot leader (․)
+        OneDotLeader => '․', "ONE DOT LEADER", ".";
+        /// Two dot leader (‥)
+        TwoDotLeader => '‥', "TWO DOT LEADER", "..";
+        /// Horizontal ellipsis (…)
+        HorizontalEllipsis => '…', "HORIZONTAL ELLIPSIS", "...";
+        /// Hyphenation point (‧)
+        HyphenationPoint => '‧', "HYPHENATION POINT", "-";
+        /// Hyphen bullet (⁃)
+        HyphenBullet => '⁃', "HYPHEN BULLET", "-";
+        /// Black leftwards bullet (⁌)
+        BlackLeftwardsBullet => '⁌', "BLACK LEFTWARDS BULLET", "<";
+        /// Black rightwards bullet (⁍)
+        BlackRightwardsBullet => '⁍', "BLACK RIGHTWARDS BULLET", ">";
+        // Arrows (U+2190 - U+21FF)
+        /// Leftwards arrow (←)
+        LeftwardsArrow => '←', "LEFTWARDS ARROW", "<-";
+        /// Upwards arrow (↑)
+        UpwardsArrow => '↑', "UPWARDS ARROW", "^";
+        /// Rightwards arrow (→)
+        RightwardsArrow => '→', "RIGHTWARDS ARROW", "->";
+        /// Downwards arrow (↓)
+        DownwardsArrow => '↓', "DOWNWARDS ARROW", "v";
+        /// Left right arrow (↔)
+        LeftRightArrow => '↔', "LEFT RIGHT ARROW", "<->";
+        /// Up down arrow (↕)
+        UpDownArrow => '↕', "UP DOWN ARROW", "|";
+        /// North west arrow (↖)
+        NorthWestArrow => '↖', "NORTH WEST ARROW", "\\";
+        /// North east arrow (↗)
+        NorthEastArrow => '↗', "NORTH EAST ARROW", "/";
+        /// South east arrow (↘)
+        SouthEastArrow => '↘', "SOUTH EAST ARROW", "\\";
+        /// South west arrow (↙)
+        SouthWestArrow => '↙', "SOUTH WEST ARROW", "/";
+        /// Leftwards arrow with stroke (↚)
+        LeftwardsArrowWithStroke => '↚', "LEFTWARDS ARROW WITH STROKE", "<-";
+        /// Rightwards arrow with stroke (↛)
+        RightwardsArrowWithStroke => '↛', "RIGHTWARDS ARROW WITH STROKE", "->";
+        /// Leftwards wave arrow (↜)
+        LeftwardsWaveArrow => '↜', "LEFTWARDS WAVE ARROW", "<-";
+        /// Rightwards wave arrow (↝)
+        RightwardsWaveArrow => '↝', "RIGHTWARDS WAVE ARROW", "->";
+        /// Leftwards two headed arrow (↞)
+        LeftwardsTwoHeadedArrow => '↞', "LEFTWARDS TWO HEADED ARROW", "<-";
+        /// Upwards two headed arrow (↟)
+        UpwardsTwoHeadedArrow => '↟', "UPWARDS TWO HEADED ARROW", "^";
+        /// Rightwards two headed arrow (↠)
+        RightwardsTwoHeadedArrow => '↠', "RIGHTWARDS TWO HEADED ARROW", "->";
+        /// Downwards two headed arrow (↡)
+        DownwardsTwoHeadedArrow => '↡', "DOWNWARDS TWO HEADED ARROW", "v";
+        /// Leftwards arrow with tail (↢)
+        LeftwardsArrowWithTail => '↢', "LEFTWARDS ARROW WITH TAIL", "<-";
+        /// Rightwards arrow with tail (↣)
+        RightwardsArrowWithTail => '↣', "RIGHTWARDS ARROW WITH TAIL", "->";
+        /// Leftwards arrow from bar (↤)
+        LeftwardsArrowFromBar => '↤', "LEFTWARDS ARROW FROM BAR", "<-";
+        /// Upwards arrow from bar (↥)
+        UpwardsArrowFromBar => '↥', "UPWARDS ARROW FROM BAR", "^";
+        /// Rightwards arrow from bar (↦)
+        RightwardsArrowFromBar => '↦', "RIGHTWARDS ARROW FROM BAR", "->";
+        /// Downwards arrow from bar (↧)
+        DownwardsArrowFromBar => '↧', "DOWNWARDS ARROW FROM BAR", "v";
+        /// Up down arrow with base (↨)
+        UpDownArrowWithBase => '↨', "UP DOWN ARROW WITH BASE", "|";
+        /// Leftwards arrow with hook (↩)
+        LeftwardsArrowWithHook => '↩', "LEFTWARDS ARROW WITH HOOK", "<-";
+        /// Rightwards arrow with hook (↪)
+        RightwardsArrowWithHook => '↪', "RIGHTWARDS ARROW WITH HOOK", "->";
+        /// Leftwards arrow with loop (↫)
+        LeftwardsArrowWithLoop => '↫', "LEFTWARDS ARROW WITH LOOP", "<-";
+        /// Rightwards arrow with loop (↬)
+        RightwardsArrowWithLoop => '↬', "RIGHTWARDS ARROW WITH LOOP", "->";
+        /// Left right wave arrow (↭)
+        LeftRightWaveArrow => '↭', "LEFT RIGHT WAVE ARROW", "<->";
+        /// Left right arrow with stroke (↮)
+        LeftRightArrowWithStroke => '↮', "LEFT RIGHT ARROW WITH STROKE", "<->";
+        /// Downwards zigzag arrow (↯)
+        DownwardsZigzagArrow => '↯', "DOWNWARDS ZIGZAG ARROW", "v";
+        /// Upwards arrow with tip leftwards (↰)
+        UpwardsArrowWithTipLeftwards => '↰', "UPWARDS ARROW WITH TIP LEFTWARDS", "^";
+        /// Upwards arrow with tip rightwards (↱)
+        UpwardsArrowWithTipRightwards => '↱', "UPWARDS ARROW WITH TIP RIGHTWARDS", "^";
+        /// Downwards arrow with tip leftwards (↲)
+        DownwardsArrowWithTipLeftwards => '↲', "DOWNWARDS ARROW WITH TIP LEFTWARDS", "<-";
+        /// Downwards arrow with tip rightwards (↳)
+        DownwardsArrowWithTipRightwards => '↳', "DOWNWARDS ARROW WITH TIP RIGHTWARDS", "->";
+        /// Rightwards arrow with corner downwards (↴)
+        RightwardsArrowWithCornerDownwards => '↴', "RIGHTWARDS ARROW WITH CORNER DOWNWARDS", "v";
+        /// Downwards arrow with corner leftwards (↵)
+        DownwardsArrowWithCornerLeftwards => '↵', "DOWNWARDS ARROW WITH CORNER LEFTWARDS", "<-";
+        /// Anticlockwise top semicircle arrow (↶)
+        AnticlockwiseTopSemicircleArrow => '↶', "ANTICLOCKWISE TOP SEMICIRCLE ARROW", "@";
+        /// Clockwise top semicircle arrow (↷)
+        ClockwiseTopSemicircleArrow => '↷', "CLOCKWISE TOP SEMICIRCLE ARROW", "@";
+        /// North west arrow to long bar (↸)
+        NorthWestArrowToLongBar => '↸', "NORTH WEST ARROW TO LONG BAR", "\\";
+        /// Leftwards arrow to bar over rightwards arrow to bar (↹)
+        LeftwardsArrowToBarOverRightwardsArrowToBar => '↹', "LEFTWARDS ARROW TO BAR OVER RIGHTWARDS ARROW TO BAR", "<->";
+        /// Anticlockwise open circle arrow (↺)
+        AnticlockwiseOpenCircleArrow => '↺', "ANTICLOCKWISE OPEN CIRCLE ARROW", "@";
+        /// Clockwise open circle arrow (↻)
+        ClockwiseOpenCircleArrow => '↻', "CLOCKWISE OPEN CIRCLE ARROW", "@";
+        /// Leftwards harpoon with barb upwards (↼)
+        LeftwardsHarpoonWithBarbUpwards => '↼', "LEFTWARDS HARPOON WITH BARB UPWARDS", "<-";
+        /// Leftwards harpoon with barb downwards (↽)
+        LeftwardsHarpoonWithBarbDownwards => '↽', "LEFTWARDS HARPOON WITH BARB DOWNWARDS", "<-";
+        /// Upwards harpoon with barb rightwards (↾)
+        UpwardsHarpoonWithBarbRightwards => '↾', "UPWARDS HARPOON WITH BARB RIGHTWARDS", "^";
+        /// Upwards harpoon with barb leftwards (↿)
+        UpwardsHarpoonWithBarbLeftwards => '↿', "UPWARDS HARPOON WITH BARB LEFTWARDS", "^";
+        /// Rightwards harpoon with barb upwards (⇀)
+        RightwardsHarpoonWithBarbUpwards => '⇀', "RIGHTWARDS HARPOON WITH BARB UPWARDS", "->";
+        /// Rightwards harpoon with barb downwards (⇁)
+        RightwardsHarpoonWithBarbDownwards => '⇁', "RIGHTWARDS HARPOON WITH BARB DOWNWARDS", "->";
+        /// Downwards harpoon with barb rightwards (⇂)
+        DownwardsHarpoonWithBarbRightwards => '⇂', "DOWNWARDS HARPOON WITH BARB RIGHTWARDS", "v";
+        /// Downwards harpoon with barb leftwards (⇃)
+        DownwardsHarpoonWithBarbLeftwards => '⇃', "DOWNWARDS HARPOON WITH BARB LEFTWARDS", "v";
+        /// Rightwards arrow over leftwards arrow (⇄)
+        RightwardsArrowOverLeftwardsArrow => '⇄', "RIGHTWARDS ARROW OVER LEFTWARDS ARROW", "<->";
+        /// Upwards arrow leftwards of downwards arrow (⇅)
+        UpwardsArrowLeftwardsOfDownwardsArrow => '⇅', "UPWARDS ARROW LEFTWARDS OF DOWNWARDS ARROW", "|";
+        /// Leftwards arrow over rightwards arrow (⇆)
+        LeftwardsArrowOverRightwardsArrow => '⇆', "LEFTWARDS ARROW OVER RIGHTWARDS ARROW", "<->";
+        /// Leftwards paired arrows (⇇)
+        LeftwardsPairedArrows => '⇇', "LEFTWARDS PAIRED ARROWS", "<-";
+        /// Upwards paired arrows (⇈)
+        UpwardsPairedArrows => '⇈', "UPWARDS PAIRED ARROWS", "^";
+        /// Rightwards paired arrows (⇉)
+        RightwardsPairedArrows => '⇉', "RIGHTWARDS PAIRED ARROWS", "->";
+        /// Downwards paired arrows (⇊)
+        DownwardsPairedArrows => '⇊', "DOWNWARDS PAIRED ARROWS", "v";
+        /// Leftwards harpoon over rightwards harpoon (⇋)
+        LeftwardsHarpoonOverRightwardsHarpoon => '⇋', "LEFTWARDS HARPOON OVER RIGHTWARDS HARPOON", "<->";
+        /// Rightwards harpoon over leftwards harpoon (⇌)
+        RightwardsHarpoonOverLeftwardsHarpoon => '⇌', "RIGHTWARDS HARPOON OVER LEFTWARDS HARPOON", "<->";
+        /// Leftwards double arrow with stroke (⇍)
+        LeftwardsDoubleArrowWithStroke => '⇍', "LEFTWARDS DOUBLE ARROW WITH STROKE", "<=";
+        /// Left right double arrow with stroke (⇎)
+        LeftRightDoubleArrowWithStroke => '⇎', "LEFT RIGHT DOUBLE ARROW WITH STROKE", "<=>";
+        /// Rightwards double arrow with stroke (⇏)
+        RightwardsDoubleArrowWithStroke => '⇏', "RIGHTWARDS DOUBLE ARROW WITH STROKE", "=>";
+        /// Leftwards double arrow (⇐)
+        LeftwardsDoubleArrow => '⇐', "LEFTWARDS DOUBLE ARROW", "<=";
+        /// Upwards double arrow (⇑)
+        UpwardsDoubleArrow => '⇑', "UPWARDS DOUBLE ARROW", "^";
+        /// Rightwards double arrow (⇒)
+        RightwardsDoubleArrow => '⇒', "RIGHTWARDS DOUBLE ARROW", "=>";
+        /// Downwards double arrow (⇓)
+        DownwardsDoubleArrow => '⇓', "DOWNWARDS DOUBLE ARROW", "v";
+        /// Left right double arrow (⇔)
+        LeftRightDoubleArrow => '⇔', "LEFT RIGHT DOUBLE ARROW", "<=>";
+        /// Up down double arrow (⇕)
+        UpDownDoubleArrow => '⇕', "UP DOWN DOUBLE ARROW", "|";
+        /// North west double arrow (⇖)
+        NorthWestDoubleArrow => '⇖', "NORTH WEST DOUBLE ARROW", "\\";
+        /// North east double arrow (⇗)
+        NorthEastDoubleArrow => '⇗', "NORTH EAST DOUBLE ARROW", "/";
+        /// South east double arrow (⇘)
+        SouthEastDoubleArrow => '⇘', "SOUTH EAST DOUBLE ARROW", "\\";
+        /// South west double arrow (⇙)
+        SouthWestDoubleArrow => '⇙', "SOUTH WEST DOUBLE ARROW", "/";
+        /// Leftwards triple arrow (⇚)
+        LeftwardsTripleArrow => '⇚', "LEFTWARDS TRIPLE ARROW", "<-";
+        /// Rightwards triple arrow (⇛)
+        RightwardsTripleArrow => '⇛', "RIGHTWARDS TRIPLE ARROW", "->";
+        /// Leftwards squiggle arrow (⇜)
+        LeftwardsSquiggleArrow => '⇜', "LEFTWARDS SQUIGGLE ARROW", "<-";
+        /// Rightwards squiggle arrow (⇝)
+        RightwardsSquiggleArrow => '⇝', "RIGHTWARDS SQUIGGLE ARROW", "->";
+        /// Upwards arrow with double stroke (⇞)
+        UpwardsArrowWithDoubleStroke => '⇞', "UPWARDS ARROW WITH DOUBLE STROKE", "^";
+        /// Downwards arrow with double stroke (⇟)
+        DownwardsArrowWithDoubleStroke => '⇟', "DOWNWARDS ARROW WITH DOUBLE STROKE", "v";
+        /// Leftwards dashed arrow (⇠)
+        LeftwardsDashedArrow => '⇠', "LEFTWARDS DASHED ARROW", "<-";
+        /// Upwards dashed arrow (⇡)
+        UpwardsDashedArrow => '⇡', "UPWARDS DASHED ARROW", "^";
+        /// Rightwards dashed arrow (⇢)
+        RightwardsDashedArrow => '⇢', "RIGHTWARDS DASHED ARROW", "->";
+        /// Downwards dashed arrow (⇣)
+        DownwardsDashedArrow => '⇣', "DOWNWARDS DASHED ARROW", "v";
+        /// Leftwards arrow to bar (⇤)
+        LeftwardsArrowToBar => '⇤', "LEFTWARDS ARROW TO BAR", "<-";
+        /// Rightwards arrow to bar (⇥)
+        RightwardsArrowToBar => '⇥', "RIGHTWARDS ARROW TO BAR", "->";
+        /// Leftwards white arrow (⇦)
+        LeftwardsWhiteArrow => '⇦', "LEFTWARDS WHITE ARROW", "<-";
+        /// Upwards white arrow (⇧)
+        UpwardsWhiteArrow => '⇧', "UPWARDS WHITE ARROW", "^";
+        /// Rightwards white arrow (⇨)
+        RightwardsWhiteArrow => '⇨', "RIGHTWARDS WHITE ARROW", "->";
+        /// Downwards white arrow (⇩)
+        DownwardsWhiteArrow => '⇩', "DOWNWARDS WHITE ARROW", "v";
+        /// Upwards white arrow from bar (⇪)
+        UpwardsWhiteArrowFromBar => '⇪', "UPWARDS WHITE ARROW FROM BAR", "^";
+        /// Upwards white arrow on pedestal (⇫)
+        UpwardsWhiteArrowOnPedestal => '⇫', "UPWARDS WHITE ARROW ON PEDESTAL", "^";
+        /// Upwards white arrow on pedestal with horizontal bar (⇬)
+        UpwardsWhiteArrowOnPedestalWithHorizontalBar => '⇬', "UPWARDS WHITE ARROW ON PEDESTAL WITH HORIZONTAL BAR", "^";
+        /// Upwards white arrow on pedestal with vertical bar (⇭)
+        UpwardsWhiteArrowOnPedestalWithVerticalBar => '⇭', "UPWARDS WHITE ARROW ON PEDESTAL WITH VERTICAL BAR", "^";
+        /// Upwards white double arrow (⇮)
+        UpwardsWhiteDoubleArrow => '⇮', "UPWARDS WHITE DOUBLE ARROW", "^";
+        /// Upwards white double arrow on pedestal (⇯)
+        UpwardsWhiteDoubleArrowOnPedestal => '⇯', "UPWARDS WHITE DOUBLE ARROW ON PEDESTAL", "^";
+        /// Rightwards white arrow from wall (⇰)
+        RightwardsWhiteArrowFromWall => '⇰', "RIGHTWARDS WHITE ARROW FROM WALL", "->";
+        /// North west arrow to corner (⇱)
+        NorthWestArrowToCorner => '⇱', "NORTH WEST ARROW TO CORNER", "\\";
+        /// South east arrow to corner (⇲)
+        SouthEastArrowToCorner => '⇲', "SOUTH EAST ARROW TO CORNER", "\\";
+        /// Up down white arrow (⇳)
+        UpDownWhiteArrow => '⇳', "UP DOWN WHITE ARROW", "|";
+        /// Right arrow with small circle (⇴)
+        RightArrowWithSmallCircle => '⇴', "RIGHT ARROW WITH SMALL CIRCLE", "->";
+        /// Downwards arrow leftwards of upwards arrow (⇵)
+        DownwardsArrowLeftwardsOfUpwardsArrow => '⇵', "DOWNWARDS ARROW LEFTWARDS OF UPWARDS ARROW", "|";
+        /// Three rightwards arrows (⇶)
+        ThreeRightwardsArrows => '⇶', "THREE RIGHTWARDS ARROWS", "->";
+        /// Leftwards arrow with vertical stroke (⇷)
+        LeftwardsArrowWithVerticalStroke => '⇷', "LEFTWARDS ARROW WITH VERTICAL STROKE", "<-";
+        /// Rightwards arrow with vertical stroke (⇸)
+        RightwardsArrowWithVerticalStroke => '⇸', "RIGHTWARDS ARROW WITH VERTICAL STROKE", "->";
+        /// Left right arrow with vertical stroke (⇹)
+        LeftRightArrowWithVerticalStroke => '⇹', "LEFT RIGHT ARROW WITH VERTICAL STROKE", "<->";
+        /// Leftwards arrow with double vertical stroke (⇺)
+        LeftwardsArrowWithDoubleVerticalStroke => '⇺', "LEFTWARDS ARROW WITH DOUBLE VERTICAL STROKE", "<=";
+        /// Rightwards arrow with double vertical stroke (⇻)
+        RightwardsArrowWithDoubleVerticalStroke => '⇻', "RIGHTWARDS ARROW WITH DOUBLE VERTICAL STROKE", "=>";
+        /// Left right arrow with double vertical stroke (⇼)
+        LeftRightArrowWithDoubleVerticalStroke => '⇼', "LEFT RIGHT ARROW WITH DOUBLE VERTICAL STROKE", "<=>";
+        /// Leftwards open-headed arrow (⇽)
+        LeftwardsOpenHeadedArrow => '⇽', "LEFTWARDS OPEN-HEADED ARROW", "<-";
+        /// Rightwards open-headed arrow (⇾)
+        RightwardsOpenHeadedArrow => '⇾', "RIGHTWARDS OPEN-HEADED ARROW", "->";
+        /// Left right open-headed arrow (⇿)
+        LeftRightOpenHeadedArrow => '⇿', "LEFT RIGHT OPEN-HEADED ARROW", "<->";
+        // Common Mathematical Operators
+        /// For all (∀)
+        ForAll => '∀', "FOR ALL", "A";
+        /// Partial differential (∂)
+        PartialDifferential => '∂', "PARTIAL DIFFERENTIAL", "d";
+        /// There exists (∃)
+        ThereExists => '∃', "THERE EXISTS", "E";
+        /// Empty set (∅)
+        EmptySet => '∅', "EMPTY SET", "0";
+        /// Increment (∆)
+        Increment => '∆', "INCREMENT", "^";
+        /// Nabla (∇)
+        Nabla => '∇', "NABLA", "V";
+        /// Element of (∈)
+        ElementOf => '∈', "ELEMENT OF", "e";
+        /// Not an element of (∉)
+        NotAnElementOf => '∉', "NOT AN ELEMENT OF", "!e";
+        /// N-ary product (∏)
+        NAryProduct => '∏', "N-ARY PRODUCT", "P";
+        /// N-ary summation (∑)
+        NArySummation => '∑', "N-ARY SUMMATION", "S";
+        /// Minus sign (−)
+        MinusSign => '−', "MINUS SIGN", "-";
+        /// Minus-or-plus sign (∓)
+        MinusOrPlusSign => '∓', "MINUS-OR-PLUS SIGN", "-+";
+        /// Ring operator (∘)
+        RingOperator => '∘', "RING OPERATOR", "o";
+        /// Bullet operator (∙)
+        BulletOperator => '∙', "BULLET OPERATOR", "*";
+        /// Square root (√)
+        SquareRoot => '√', "SQUARE ROOT", "V";
+        /// Proportional to (∝)
+        ProportionalTo => '∝', "PROPORTIONAL TO", "~";
+        /// Infinity (∞)
+        Infinity => '∞', "INFINITY", "inf";
+        /// Logical and (∧)
+        LogicalAnd => '∧', "LOGICAL AND", "&";
+        /// Logical or (∨)
+        LogicalOr => '∨', "LOGICAL OR", "|";
+        /// Intersection (∩)
+        Intersection => '∩', "INTERSECTION", "n";
+        /// Union (∪)
+        Union => '∪', "UNION", "U";
+        /// Integral (∫)
+        Integral => '∫', "INTEGRAL", "S";
+        /// Therefore (∴)
+        Therefore => '∴', "THEREFORE", ":.";
+        /// Because (∵)
+        Because => '∵', "BECAUSE", ".:";
+        /// Tilde operator (∼)
+        TildeOperator => '∼', "TILDE OPERATOR", "~";
+        /// Almost equal to (≈)
+        AlmostEqualTo => '≈', "ALMOST EQUAL TO", "~=";
+        /// Not equal to (≠)
+        NotEqualTo => '≠', "NOT EQUAL TO", "!=";
+        /// Identical to (≡)
+        IdenticalTo => '≡', "IDENTICAL TO", "==";
+        /// Less-than or equal to (≤)
+        LessThanOrEqualTo => '≤', "LESS-THAN OR EQUAL TO", "<=";
+        /// Greater-than or equal to (≥)
+        GreaterThanOrEqualTo => '≥', "GREATER-THAN OR EQUAL TO", ">=";
+        /// Much less-than (≪)
+        MuchLessThan => '≪', "MUCH LESS-THAN", "<<";
+        /// Much greater-than (≫)
+        MuchGreaterThan => '≫', "MUCH GREATER-THAN", ">>";
+        /// Subset of (⊂)
+        SubsetOf => '⊂', "SUBSET OF", "<";
+        /// Superset of (⊃)
+        SupersetOf => '⊃', "SUPERSET OF", ">";
+        /// Subset of or equal to (⊆)
+        SubsetOfOrEqualTo => '⊆', "SUBSET OF OR EQUAL TO", "<=";
+        /// Superset of or equal to (⊇)
+        SupersetOfOrEqualTo => '⊇', "SUPERSET OF OR EQUAL TO", ">=";
+        // Miscellaneous Symbols
+        /// Warning sign (⚠)
+        WarningSign => '⚠', "WARNING SIGN", "!";
+        // Dingbats check and cross marks
+        /// Check mark (✓)
+        CheckMark => '✓', "CHECK MARK", "v";
+        /// Heavy check mark (✔)
+        HeavyCheckMark => '✔', "HEAVY CHECK MARK", "v";
+        /// Multiplication x (✕)
+        MultiplicationX => '✕', "MULTIPLICATION X", "x";
+        /// Heavy multiplication x (✖)
+        HeavyMultiplicationX => '✖', "HEAVY MULTIPLICATION X", "x";
+        /// Ballot x (✗)
+        BallotX => '✗', "BALLOT X", "x";
+        /// Heavy ballot x (✘)
+        HeavyBallotX => '✘', "HEAVY BALLOT X", "x";
+        // Box Drawing (U+2500 - U+257F)
+        /// Box drawings light horizontal (─)
+        BoxDrawingsLightHorizontal => '─', "BOX DRAWINGS LIGHT HORIZONTAL", "-";
+        /// Box drawings heavy horizontal (━)
+        BoxDrawingsHeavyHorizontal => '━', "BOX DRAWINGS HEAVY HORIZONTAL", "-";
+        /// Box drawings light vertical (│)
+        BoxDrawingsLightVertical => '│', "BOX DRAWINGS LIGHT VERTICAL", "|";
+        /// Box drawings heavy vertical (┃)
+        BoxDrawingsHeavyVertical => '┃', "BOX DRAWINGS HEAVY VERTICAL", "|";
+        /// Box drawings light triple dash horizontal (┄)
+        BoxDrawingsLightTripleDashHorizontal => '┄', "BOX DRAWINGS LIGHT TRIPLE DASH HORIZONTAL", "-";
+        /// Box drawings heavy triple dash horizontal (┅)
+        BoxDrawingsHeavyTripleDashHorizontal => '┅', "BOX DRAWINGS HEAVY TRIPLE DASH HORIZONTAL", "-";
+        /// Box drawings light triple dash vertical (┆)
+        BoxDrawingsLightTripleDashVertical => '┆', "BOX DRAWINGS LIGHT TRIPLE DASH VERTICAL", "|";
+        /// Box drawings heavy triple dash vertical (┇)
+        BoxDrawingsHeavyTripleDashVertical => '┇', "BOX DRAWINGS HEAVY TRIPLE DASH VERTICAL", "|";
+        /// Box drawings light quadruple dash horizontal (┈)
+        BoxDrawingsLightQuadrupleDashHorizontal => '┈', "BOX DRAWINGS LIGHT QUADRUPLE DASH HORIZONTAL", "-";
+        /// Box drawings heavy quadruple dash horizontal (┉)
+        BoxDrawingsHeavyQuadrupleDashHorizontal => '┉', "BOX DRAWINGS HEAVY QUADRUPLE DASH HORIZONTAL", "-";
+        /// Box drawings light quadruple dash vertical (┊)
+        BoxDrawingsLightQuadrupleDashVertical => '┊', "BOX DRAWINGS LIGHT QUADRUPLE DASH VERTICAL", "|";
+        /// Box drawings heavy quadruple dash vertical (┋)
+        BoxDrawingsHeavyQuadrupleDashVertical => '┋', "BOX DRAWINGS HEAVY QUADRUPLE DASH VERTICAL", "|";
+        /// Box drawings light down and right (┌)
+        BoxDrawingsLightDownAndRight => '┌', "BOX DRAWINGS LIGHT DOWN AND RIGHT", "+";
+        /// Box drawings down light and right heavy (┍)
+        BoxDrawingsDownLightAndRightHeavy => '┍', "BOX DRAWINGS DOWN LIGHT AND RIGHT HEAVY", "+";
+        /// Box drawings down heavy and right light (┎)
+        BoxDrawingsDownHeavyAndRightLight => '┎', "BOX DRAWINGS DOWN HEAVY AND RIGHT LIGHT", "+";
+        /// Box drawings heavy down and right (┏)
+        BoxDrawingsHeavyDownAndRight => '┏', "BOX DRAWINGS HEAVY DOWN AND RIGHT", "+";
+        /// Box drawings light down and left (┐)
+        BoxDrawingsLightDownAndLeft => '┐', "BOX DRAWINGS LIGHT DOWN AND LEFT", "+";
+        /// Box drawings down light and left heavy (┑)
+        BoxDrawingsDownLightAndLeftHeavy => '┑', "BOX DRAWINGS DOWN LIGHT AND LEFT HEAVY", "+";
+        /// Box drawings down heavy and left light (┒)
+        BoxDrawingsDownHeavyAndLeftLight => '┒', "BOX DRAWINGS DOWN HEAVY AND LEFT LIGHT", "+";
+        /// Box drawings heavy down and left (┓)
+        BoxDrawingsHeavyDownAndLeft => '┓', "BOX DRAWINGS HEAVY DOWN AND LEFT", "+";
+        /// Box drawings light up and right (└)
+        BoxDrawingsLightUpAndRight => '└', "BOX DRAWINGS LIGHT UP AND RIGHT", "+";
+        /// Box drawings up light and right heavy (┕)
+        BoxDrawingsUpLightAndRightHeavy => '┕', "BOX DRAWINGS UP LIGHT AND RIGHT HEAVY", "+";
+        /// Box drawings up heavy and right light (┖)
+        BoxDrawingsUpHeavyAndRightLight => '┖', "BOX DRAWINGS UP HEAVY AND RIGHT LIGHT", "+";
+        /// Box drawings heavy up and right (┗)
+        BoxDrawingsHeavyUpAndRight => '┗', "BOX DRAWINGS HEAVY UP AND RIGHT", "+";
+        /// Box drawings light up and left (┘)
+        BoxDrawingsLightUpAndLeft => '┘', "BOX DRAWINGS LIGHT UP AND LEFT", "+";
+        /// Box drawings up light and left heavy (┙)
+        BoxDrawingsUpLightAndLeftHeavy => '┙', "BOX DRAWINGS UP LIGHT AND LEFT HEAVY", "+";
+        /// Box drawings up heavy and left light (┚)
+        BoxDrawingsUpHeavyAndLeftLight => '┚', "BOX DRAWINGS UP HEAVY AND LEFT LIGHT", "+";
+        /// Box drawings heavy up and left (┛)
+        BoxDrawingsHeavyUpAndLeft => '┛', "BOX DRAWINGS HEAVY UP AND LEFT", "+";
+        /// Box drawings light vertical and right (├)
+        BoxDrawingsLightVerticalAndRight => '├', "BOX DRAWINGS LIGHT VERTICAL AND RIGHT", "+";
+        /// Box drawings vertical light and right heavy (┝)
+        BoxDrawingsVerticalLightAndRightHeavy => '┝', "BOX DRAWINGS VERTICAL LIGHT AND RIGHT HEAVY", "+";
+        /// Box drawings up heavy and right down light (┞)
+        BoxDrawingsUpHeavyAndRightDownLight => '┞', "BOX DRAWINGS UP HEAVY AND RIGHT DOWN LIGHT", "+";
+        /// Box drawings down heavy and right up light (┟)
+        BoxDrawingsDownHeavyAndRightUpLight => '┟', "BOX DRAWINGS DOWN HEAVY AND RIGHT UP LIGHT", "+";
+        /// Box drawings vertical heavy and right light (┠)
+        BoxDrawingsVerticalHeavyAndRightLight => '┠', "BOX DRAWINGS VERTICAL HEAVY AND RIGHT LIGHT", "+";
+        /// Box drawings down light and right up heavy (┡)
+        BoxDrawingsDownLightAndRightUpHeavy => '┡', "BOX DRAWINGS DOWN LIGHT AND RIGHT UP HEAVY", "+";
+        /// Box drawings up light and right down heavy (┢)
+        BoxDrawingsUpLightAndRightDownHeavy => '┢', "BOX DRAWINGS UP LIGHT AND RIGHT DOWN HEAVY", "+";
+        /// Box drawings heavy vertical and right (┣)
+        BoxDrawingsHeavyVerticalAndRight => '┣', "BOX DRAWINGS HEAVY VERTICAL AND RIGHT", "+";
+        /// Box drawings light vertical and left (┤)
+        BoxDrawingsLightVerticalAndLeft => '┤', "BOX DRAWINGS LIGHT VERTICAL AND LEFT", "+";
+        /// Box drawings vertical light and left heavy (┥)
+        BoxDrawingsVerticalLightAndLeftHeavy => '┥', "BOX DRAWINGS VERTICAL LIGHT AND LEFT HEAVY", "+";
+        /// Box drawings up heavy and left down light (┦)
+        BoxDrawingsUpHeavyAndLeftDownLight => '┦', "BOX DRAWINGS UP HEAVY AND LEFT DOWN LIGHT", "+";
+        /// Box drawings down heavy and left up light (┧)
+        BoxDrawingsDownHeavyAndLeftUpLight => '┧', "BOX DRAWINGS DOWN HEAVY AND LEFT UP LIGHT", "+";
+        /// Box drawings vertical heavy and left light (┨)
+        BoxDrawingsVerticalHeavyAndLeftLight => '┨', "BOX DRAWINGS VERTICAL HEAVY AND LEFT LIGHT", "+";
+        /// Box drawings down light and left up heavy (┩)
+        BoxDrawingsDownLightAndLeftUpHeavy => '┩', "BOX DRAWINGS DOWN LIGHT AND LEFT UP HEAVY", "+";
+        /// Box drawings up light and left down heavy (┪)
+        BoxDrawingsUpLightAndLeftDownHeavy => '┪', "BOX DRAWINGS UP LIGHT AND LEFT DOWN HEAVY", "+";
+        /// Box drawings heavy vertical and left (┫)
+        BoxDrawingsHeavyVerticalAndLeft => '┫', "BOX DRAWINGS HEAVY VERTICAL AND LEFT", "+";
+        /// Box drawings light down and horizontal (┬)
+        BoxDrawingsLightDownAndHorizontal => '┬', "BOX DRAWINGS LIGHT DOWN AND HORIZONTAL", "+";
+        /// Box drawings left heavy and right down light (┭)
+        BoxDrawingsLeftHeavyAndRightDownLight => '┭', "BOX DRAWINGS LEFT HEAVY AND RIGHT DOWN LIGHT", "+";
+        /// Box drawings right heavy and left down light (┮)
+        BoxDrawingsRightHeavyAndLeftDownLight => '┮', "BOX DRAWINGS RIGHT HEAVY AND LEFT DOWN LIGHT", "+";
+        /// Box drawings down light and horizontal heavy (┯)
+        BoxDrawingsDownLightAndHorizontalHeavy => '┯', "BOX DRAWINGS DOWN LIGHT AND HORIZONTAL HEAVY", "+";
+        /// Box drawings down heavy and horizontal light (┰)
+        BoxDrawingsDownHeavyAndHorizontalLight => '┰', "BOX DRAWINGS DOWN HEAVY AND HORIZONTAL LIGHT", "+";
+        /// Box drawings right light and left down heavy (┱)
+        BoxDrawingsRightLightAndLeftDownHeavy => '┱', "BOX DRAWINGS RIGHT LIGHT AND LEFT DOWN HEAVY", "+";
+        /// Box drawings left light and right down heavy (┲)
+        BoxDrawingsLeftLightAndRightDownHeavy => '┲', "BOX DRAWINGS LEFT LIGHT AND RIGHT DOWN HEAVY", "+";
+        /// Box drawings heavy down and horizontal (┳)
+        BoxDrawingsHeavyDownAndHorizontal => '┳', "BOX DRAWINGS HEAVY DOWN AND HORIZONTAL", "+";
+        /// Box drawings light up and horizontal (┴)
+        BoxDrawingsLightUpAndHorizontal => '┴', "BOX DRAWINGS LIGHT UP AND HORIZONTAL", "+";
+        /// Box drawings left heavy and right up light (┵)
+        BoxDrawingsLeftHeavyAndRightUpLight => '┵', "BOX DRAWINGS LEFT HEAVY AND RIGHT UP LIGHT", "+";
+        /// Box drawings right heavy and left up light (┶)
+        BoxDrawingsRightHeavyAndLeftUpLight => '┶', "BOX DRAWINGS RIGHT HEAVY AND LEFT UP LIGHT", "+";
+        /// Box drawings up light and horizontal heavy (┷)
+        BoxDrawingsUpLightAndHorizontalHeavy => '┷', "BOX DRAWINGS UP LIGHT AND HORIZONTAL HEAVY", "+";
+        /// Box drawings up heavy and horizontal light (┸)
+        BoxDrawingsUpHeavyAndHorizontalLight => '┸', "BOX DRAWINGS UP HEAVY AND HORIZONTAL LIGHT", "+";
+        /// Box drawings right light and left up heavy (┹)
+        BoxDrawingsRightLightAndLeftUpHeavy => '┹', "BOX DRAWINGS RIGHT LIGHT AND LEFT UP HEAVY", "+";
+        /// Box drawings left light and right up heavy (┺)
+        BoxDrawingsLeftLightAndRightUpHeavy => '┺', "BOX DRAWINGS LEFT LIGHT AND RIGHT UP HEAVY", "+";
+        /// Box drawings heavy up and horizontal (┻)
+        BoxDrawingsHeavyUpAndHorizontal => '┻', "BOX DRAWINGS HEAVY UP AND HORIZONTAL", "+";
+        /// Box drawings light vertical and horizontal (┼)
+        BoxDrawingsLightVerticalAndHorizontal => '┼', "BOX DRAWINGS LIGHT VERTICAL AND HORIZONTAL", "+";
+        /// Box drawings left heavy and right vertical light (┽)
+        BoxDrawingsLeftHeavyAndRightVerticalLight => '┽', "BOX DRAWINGS LEFT HEAVY AND RIGHT VERTICAL LIGHT", "+";
+        /// Box drawings right heavy and left vertical light (┾)
+        BoxDrawingsRightHeavyAndLeftVerticalLight => '┾', "BOX DRAWINGS RIGHT HEAVY AND LEFT VERTICAL LIGHT", "+";
+        /// Box drawings vertical light and horizontal heavy (┿)
+        BoxDrawingsVerticalLightAndHorizontalHeavy => '┿', "BOX DRAWINGS VERTICAL LIGHT AND HORIZONTAL HEAVY", "+";
+        /// Box drawings up heavy and down horizontal light (╀)
+        BoxDrawingsUpHeavyAndDownHorizontalLight => '╀', "BOX DRAWINGS UP HEAVY AND DOWN HORIZONTAL LIGHT", "+";
+        /// Box drawings down heavy and up horizontal light (╁)
+        BoxDrawingsDownHeavyAndUpHorizontalLight => '╁', "BOX DRAWINGS DOWN HEAVY AND UP HORIZONTAL LIGHT", "+";
+        /// Box drawings vertical heavy and horizontal light (╂)
+        BoxDrawingsVerticalHeavyAndHorizontalLight => '╂', "BOX DRAWINGS VERTICAL HEAVY AND HORIZONTAL LIGHT", "+";
+        /// Box drawings left up heavy and right down light (╃)
+        BoxDrawingsLeftUpHeavyAndRightDownLight => '╃', "BOX DRAWINGS LEFT UP HEAVY AND RIGHT DOWN LIGHT", "+";
+        /// Box drawings right up heavy and left down light (╄)
+        BoxDrawingsRightUpHeavyAndLeftDownLight => '╄', "BOX DRAWINGS RIGHT UP HEAVY AND LEFT DOWN LIGHT", "+";
+        /// Box drawings left down heavy and right up light (╅)
+        BoxDrawingsLeftDownHeavyAndRightUpLight => '╅', "BOX DRAWINGS LEFT DOWN HEAVY AND RIGHT UP LIGHT", "+";
+        /// Box drawings right down heavy and left up light (╆)
+        BoxDrawingsRightDownHeavyAndLeftUpLight => '╆', "BOX DRAWINGS RIGHT DOWN HEAVY AND LEFT UP LIGHT", "+";
+        /// Box drawings down light and up horizontal heavy (╇)
+        BoxDrawingsDownLightAndUpHorizontalHeavy => '╇', "BOX DRAWINGS DOWN LIGHT AND UP HORIZONTAL HEAVY", "+";
+        /// Box drawings up light and down horizontal heavy (╈)
+        BoxDrawingsUpLightAndDownHorizontalHeavy => '╈', "BOX DRAWINGS UP LIGHT AND DOWN HORIZONTAL HEAVY", "+";
+        /// Box drawings right light and left vertical heavy (╉)
+        BoxDrawingsRightLightAndLeftVerticalHeavy => '╉', "BOX DRAWINGS RIGHT LIGHT AND LEFT VERTICAL HEAVY", "+";
+        /// Box drawings left light and right vertical heavy (╊)
+        BoxDrawingsLeftLightAndRightVerticalHeavy => '╊', "BOX DRAWINGS LEFT LIGHT AND RIGHT VERTICAL HEAVY", "+";
+        /// Box drawings heavy vertical and horizontal (╋)
+        BoxDrawingsHeavyVerticalAndHorizontal => '╋', "BOX DRAWINGS HEAVY VERTICAL AND HORIZONTAL", "+";
+        /// Box drawings light double dash horizontal (╌)
+        BoxDrawingsLightDoubleDashHorizontal => '╌', "BOX DRAWINGS LIGHT DOUBLE DASH HORIZONTAL", "-";
+        /// Box drawings heavy double dash horizontal (╍)
+        BoxDrawingsHeavyDoubleDashHorizontal => '╍', "BOX DRAWINGS HEAVY DOUBLE DASH HORIZONTAL", "-";
+        /// Box drawings light double dash vertical (╎)
+        BoxDrawingsLightDoubleDashVertical => '╎', "BOX DRAWINGS LIGHT DOUBLE DASH VERTICAL", "|";
+        /// Box drawings heavy double dash vertical (╏)
+        BoxDrawingsHeavyDoubleDashVertical => '╏', "BOX DRAWINGS HEAVY DOUBLE DASH VERTICAL", "|";
+        /// Box drawings double horizontal (═)
+        BoxDrawingsDoubleHorizontal => '═', "BOX DRAWINGS DOUBLE HORIZONTAL", "=";
+        /// Box drawings double vertical (║)
+        BoxDrawingsDoubleVertical => '║', "BOX DRAWINGS DOUBLE VERTICAL", "|";
+        /// Box drawings down single and right double (╒)
+        BoxDrawingsDownSingleAndRightDouble => '╒', "BOX DRAWINGS DOWN SINGLE AND RIGHT DOUBLE", "+";
+        /// Box drawings down double and right single (╓)
+        BoxDrawingsDownDoubleAndRightSingle => '╓', "BOX DRAWINGS DOWN DOUBLE AND RIGHT SINGLE", "+";
+        /// Box drawings double down and right (╔)
+        BoxDrawingsDoubleDownAndRight => '╔', "BOX DRAWINGS DOUBLE DOWN AND RIGHT", "+";
+        /// Box drawings down single and left double (╕)
+        BoxDrawingsDownSingleAndLeftDouble => '╕', "BOX DRAWINGS DOWN SINGLE AND LEFT DOUBLE", "+";
+        /// Box drawings down double and left single (╖)
+        BoxDrawingsDownDoubleAndLeftSingle => '╖', "BOX DRAWINGS DOWN DOUBLE AND LEFT SINGLE", "+";
+        /// Box drawings double down and left (╗)
+        BoxDrawingsDoubleDownAndLeft => '╗', "BOX DRAWINGS DOUBLE DOWN AND LEFT", "+";
+        /// Box drawings up single and right double (╘)
+        BoxDrawingsUpSingleAndRightDouble => '╘', "BOX DRAWINGS UP SINGLE AND RIGHT DOUBLE", "+";
+        /// Box drawings up double and right single (╙)
+        BoxDrawingsUpDoubleAndRightSingle => '╙', "BOX DRAWINGS UP DOUBLE AND RIGHT SINGLE", "+";
+        /// Box drawings double up and right (╚)
+        BoxDrawingsDoubleUpAndRight => '╚', "BOX DRAWINGS DOUBLE UP AND RIGHT", "+";
+        /// Box drawings up single and left double (╛)
+        BoxDrawingsUpSingleAndLeftDouble => '╛', "BOX DRAWINGS UP SINGLE AND LEFT DOUBLE", "+";
+        /// Box drawings up double and left single (╜)
+        BoxDrawingsUpDoubleAndLeftSingle => '╜', "BOX DRAWINGS UP DOUBLE AND LEFT SINGLE", "+";
+        /// Box drawings double up and left (╝)
+        BoxDrawingsDoubleUpAndLeft => '╝', "BOX DRAWINGS DOUBLE UP AND LEFT", "+";
+        /// Box drawings vertical single and right double (╞)
+        BoxDrawingsVerticalSingleAndRightDouble => '╞', "BOX DRAWINGS VERTICAL SINGLE AND RIGHT DOUBLE", "+";
+        /// Box drawings vertical double and right single (╟)
+        BoxDrawingsVerticalDoubleAndRightSingle => '╟', "BOX DRAWINGS VERTICAL DOUBLE AND RIGHT SINGLE", "+";
+        /// Box drawings double vertical and right (╠)
+        BoxDrawingsDoubleVerticalAndRight => '╠', "BOX DRAWINGS DOUBLE VERTICAL AND RIGHT", "+";
+        /// Box drawings vertical single and left double (╡)
+        BoxDrawingsVerticalSingleAndLeftDouble => '╡', "BOX DRAWINGS VERTICAL SINGLE AND LEFT DOUBLE", "+";
+        /// Box drawings vertical double and left single (╢)
+        BoxDrawingsVerticalDoubleAndLeftSingle => '╢', "BOX DRAWINGS VERTICAL DOUBLE AND LEFT SINGLE", "+";
+        /// Box drawings double vertical and left (╣)
+        BoxDrawingsDoubleVerticalAndLeft => '╣', "BOX DRAWINGS DOUBLE VERTICAL AND LEFT", "+";
+        /// Box drawings down single and horizontal double (╤)
+        BoxDrawingsDownSingleAndHorizontalDouble => '╤', "BOX DRAWINGS DOWN SINGLE AND HORIZONTAL DOUBLE", "+";
+        /// Box drawings down double and horizontal single (╥)
+        BoxDrawingsDownDoubleAndHorizontalSingle => '╥', "BOX DRAWINGS DOWN DOUBLE AND HORIZONTAL SINGLE", "+";
+        /// Box drawings double down and horizontal (╦)
+        BoxDrawingsDoubleDownAndHorizontal => '╦', "BOX DRAWINGS DOUBLE DOWN AND HORIZONTAL", "+";
+        /// Box drawings up single and horizontal double (╧)
+        BoxDrawingsUpSingleAndHorizontalDouble => '╧', "BOX DRAWINGS UP SINGLE AND HORIZONTAL DOUBLE", "+";
+        /// Box drawings up double and horizontal single (╨)
+        BoxDrawingsUpDoubleAndHorizontalSingle => '╨', "BOX DRAWINGS UP DOUBLE AND HORIZONTAL SINGLE", "+";
+        /// Box drawings double up and horizontal (╩)
+        BoxDrawingsDoubleUpAndHorizontal => '╩', "BOX DRAWINGS DOUBLE UP AND HORIZONTAL", "+";
+        /// Box drawings vertical single and horizontal double (╪)
+        BoxDrawingsVerticalSingleAndHorizontalDouble => '╪', "BOX DRAWINGS VERTICAL SINGLE AND HORIZONTAL DOUBLE", "+";
+        /// Box drawings vertical double and horizontal single (╫)
+        BoxDrawingsVerticalDoubleAndHorizontalSingle => '╫', "BOX DRAWINGS VERTICAL DOUBLE AND HORIZONTAL SINGLE", "+";
+        /// Box drawings double vertical and horizontal (╬)
+        BoxDrawingsDoubleVerticalAndHorizontal => '╬', "BOX DRAWINGS DOUBLE VERTICAL AND HORIZONTAL", "+";
+        /// Box drawings light arc down and right (╭)
+        BoxDrawingsLightArcDownAndRight => '╭', "BOX DRAWINGS LIGHT ARC DOWN AND RIGHT", "+";
+        /// Box drawings light arc down and left (╮)
+        BoxDrawingsLightArcDownAndLeft => '╮', "BOX DRAWINGS LIGHT ARC DOWN AND LEFT", "+";
+        /// Box drawings light arc up and left (╯)
+        BoxDrawingsLightArcUpAndLeft => '╯', "BOX DRAWINGS LIGHT ARC UP AND LEFT", "+";
+        /// Box drawings light arc up and right (╰)
+        BoxDrawingsLightArcUpAndRight => '╰', "BOX DRAWINGS LIGHT ARC UP AND RIGHT", "+";
+        /// Box drawings light diagonal upper right to lower left (╱)
+        BoxDrawingsLightDiagonalUpperRightToLowerLeft => '╱', "BOX DRAWINGS LIGHT DIAGONAL UPPER RIGHT TO LOWER LEFT", "/";
+        /// Box drawings light diagonal upper left to lower right (╲)
+        BoxDrawingsLightDiagonalUpperLeftToLowerRight => '╲', "BOX DRAWINGS LIGHT DIAGONAL UPPER LEFT TO LOWER RIGHT", "\\";
+        /// Box drawings light diagonal cross (╳)
+        BoxDrawingsLightDiagonalCross => '╳', "BOX DRAWINGS LIGHT DIAGONAL CROSS", "X";
+        /// Box drawings light left (╴)
+        BoxDrawingsLightLeft => '╴', "BOX DRAWINGS LIGHT LEFT", "-";
+        /// Box drawings light up (╵)
+        BoxDrawingsLightUp => '╵', "BOX DRAWINGS LIGHT UP", "|";
+        /// Box drawings light right (╶)
+        BoxDrawingsLightRight => '╶', "BOX DRAWINGS LIGHT RIGHT", "-";
+        /// Box drawings light down (╷)
+        BoxDrawingsLightDown => '╷', "BOX DRAWINGS LIGHT DOWN", "|";
+        /// Box drawings heavy left (╸)
+        BoxDrawingsHeavyLeft => '╸', "BOX DRAWINGS HEAVY LEFT", "-";
+        /// Box drawings heavy up (╹)
+        BoxDrawingsHeavyUp => '╹', "BOX DRAWINGS HEAVY UP", "|";
+        /// Box drawings heavy right (╺)
+        BoxDrawingsHeavyRight => '╺', "BOX DRAWINGS HEAVY RIGHT", "-";
+        /// Box drawings heavy down (╻)
+        BoxDrawingsHeavyDown => '╻', "BOX DRAWINGS HEAVY DOWN", "|";
+        /// Box drawings light left and heavy right (╼)
+        BoxDrawingsLightLeftAndHeavyRight => '╼', "BOX DRAWINGS LIGHT LEFT AND HEAVY RIGHT", "-";
+        /// Box drawings light up and heavy down (╽)
+        BoxDrawingsLightUpAndHeavyDown => '╽', "BOX DRAWINGS LIGHT UP AND HEAVY DOWN", "|";
+        /// Box drawings heavy left and light right (╾)
+        BoxDrawingsHeavyLeftAndLightRight => '╾', "BOX DRAWINGS HEAVY LEFT AND LIGHT RIGHT", "-";
+        /// Box drawings heavy up and light down (╿)
+        BoxDrawingsHeavyUpAndLightDown => '╿', "BOX DRAWINGS HEAVY UP AND LIGHT DOWN", "|";
+    }
 }
 
 impl std::fmt::Display for Utf8 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.with_charset(charset()))
+    }
+}
+
+/// The set of characters `Utf8` is rendered with.
+///
+/// Legacy code-page consoles and serial links often cannot display the Unicode characters, in
+/// which case `Charset::Ascii` substitutes each of them with a close ASCII equivalent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Charset {
+    /// Render the Unicode characters themselves
+    #[default]
+    Unicode,
+    /// Render the ASCII substitutes returned by `Utf8::ascii`
+    Ascii,
+}
+
+impl Charset {
+    /// Detects the charset supported by the terminal from the locale environment variables.
+    ///
+    /// The first non-empty variable of `LC_ALL`, `LC_CTYPE` and `LANG` is inspected; when it does
+    /// not name a UTF-8 codeset (e.g. `C` or `en_US.ISO-8859-1`) `Charset::Ascii` is returned.
+    /// Without any locale variables set, `Charset::Unicode` is assumed.
+    pub fn detect() -> Charset {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"].iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty());
+
+        match locale {
+            Some(locale) => Self::from_locale(&locale),
+            None => Charset::Unicode,
+        }
+    }
+
+    /// Returns the charset supported by a locale string such as `en_US.UTF-8`.
+    pub fn from_locale(locale: &str) -> Charset {
+        let locale = locale.to_ascii_uppercase().replace('-', "");
+        if locale.contains("UTF8") { Charset::Unicode } else { Charset::Ascii }
+    }
+}
+
+const CHARSET_UNICODE: u8 = 0;
+const CHARSET_ASCII: u8 = 1;
+
+static CHARSET: std::sync::atomic::AtomicU8 = std::sync::atomic::AtomicU8::new(CHARSET_UNICODE);
+
+/// Sets the charset used by `Utf8`'s `Display` implementation and `repeat`.
+///
+/// The default is `Charset::Unicode`; call `set_charset(Charset::detect())` at startup to follow
+/// the user's locale instead.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// set_charset(Charset::Ascii);
+/// assert_eq!(format!("{}{}", Utf8::NodePipeSlim, Utf8::HPipeSlim.repeat(2)), "`--");
+/// set_charset(Charset::Unicode);
+/// assert_eq!(Utf8::VPipeSlim.to_string(), "│");
+/// ```
+///
+pub fn set_charset(charset: Charset) {
+    let value = match charset {
+        Charset::Unicode => CHARSET_UNICODE,
+        Charset::Ascii => CHARSET_ASCII,
+    };
+    CHARSET.store(value, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Returns the charset currently used by `Utf8`'s `Display` implementation.
+pub fn charset() -> Charset {
+    match CHARSET.load(std::sync::atomic::Ordering::Relaxed) {
+        CHARSET_ASCII => Charset::Ascii,
+        _ => Charset::Unicode,
+    }
+}
+
+/// A `Utf8` character bound to an explicit `Charset`, independent of the global setting.
+///
+/// Created with `Utf8::with_charset`, useful for renderers that carry their own charset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Glyph {
+    pub character: Utf8,
+    pub charset: Charset,
+}
+
+impl Glyph {
+    /// Repeats the rendered glyph `n` times.
+    pub fn repeat(&self, n: usize) -> String {
+        self.to_string().repeat(n)
+    }
+}
+
+impl std::fmt::Display for Glyph {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.charset {
+            Charset::Unicode => write!(f, "{}", self.character.as_char()),
+            Charset::Ascii => write!(f, "{}", self.character.ascii()),
+        }
+    }
+}
+
+/// The Unicode blocks the characters in `Utf8` are drawn from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnicodeBlock {
+    SpacingModifierLetters,
+    UnifiedCanadianAboriginalSyllabics,
+    GeneralPunctuation,
+    Arrows,
+    MathematicalOperators,
+    BoxDrawing,
+    BlockElements,
+    GeometricShapes,
+    MiscellaneousSymbols,
+    Dingbats,
+}
+
+impl UnicodeBlock {
+    /// Returns the block containing `c`, or `None` if it is not one of the blocks listed above.
+    pub const fn of(c: char) -> Option<UnicodeBlock> {
+        match c as u32 {
+            0x02B0..=0x02FF => Some(UnicodeBlock::SpacingModifierLetters),
+            0x1400..=0x167F => Some(UnicodeBlock::UnifiedCanadianAboriginalSyllabics),
+            0x2000..=0x206F => Some(UnicodeBlock::GeneralPunctuation),
+            0x2190..=0x21FF => Some(UnicodeBlock::Arrows),
+            0x2200..=0x22FF => Some(UnicodeBlock::MathematicalOperators),
+            0x2500..=0x257F => Some(UnicodeBlock::BoxDrawing),
+            0x2580..=0x259F => Some(UnicodeBlock::BlockElements),
+            0x25A0..=0x25FF => Some(UnicodeBlock::GeometricShapes),
+            0x2600..=0x26FF => Some(UnicodeBlock::MiscellaneousSymbols),
+            0x2700..=0x27BF => Some(UnicodeBlock::Dingbats),
+            _ => None,
+        }
+    }
+
+    /// Returns the official name of the block, e.g. `"Box Drawing"`.
+    pub const fn name(&self) -> &'static str {
         match self {
-            Utf8::ModLetterLeftArrowhead => write!(f, "˂"),
-            Utf8::ModLetterRightArrowhead => write!(f, "˃"),
-            Utf8::ModLetterUpArrowhead => write!(f, "˄"),
-            Utf8::ModLetterDownArrowhead => write!(f, "˅"),
-            Utf8::EndGuardArea => write!(f, "—"),
-            Utf8::CanadianSyllabicsE => write!(f, "ᐁ"),
-            Utf8::CanadianSyllabicsI => write!(f, "ᐃ"),
-            Utf8::CanadianSyllabicsO => write!(f, "ᐅ"),
-            Utf8::CanadianSyllabicsA => write!(f, "ᐊ"),
-            Utf8::TriangleDown => write!(f, "ᐁ"),
-            Utf8::TriangleUp => write!(f, "ᐃ"),
-            Utf8::TriangleRight => write!(f, "ᐅ"),
-            Utf8::TriangleLeft => write!(f, "ᐊ"),
-            Utf8::HPipeSlim => write!(f, "—"),
-            Utf8::VPipeSlim => write!(f, "│"),
-            Utf8::JointPipeSlim => write!(f, "├"),
-            Utf8::NodePipeSlim => write!(f, "└"),
-            Utf8::NodePipeCurved => write!(f, "╰"),
-            Utf8::HPipeBold => write!(f, "━"),
-            Utf8::VPipeBold => write!(f, "┃"),
-            Utf8::JointPipeBold => write!(f, "┣"),
-            Utf8::NodePipeBold => write!(f, "┗"),
-            Utf8::HPipeDouble => write!(f, "═"),
-            Utf8::VPipeDouble => write!(f, "║"),
-            Utf8::JointPipeDouble => write!(f, "╠"),
-            Utf8::NodePipeDouble => write!(f, "╚"),
+            UnicodeBlock::SpacingModifierLetters => "Spacing Modifier Letters",
+            UnicodeBlock::UnifiedCanadianAboriginalSyllabics => "Unified Canadian Aboriginal Syllabics",
+            UnicodeBlock::GeneralPunctuation => "General Punctuation",
+            UnicodeBlock::Arrows => "Arrows",
+            UnicodeBlock::MathematicalOperators => "Mathematical Operators",
+            UnicodeBlock::BoxDrawing => "Box Drawing",
+            UnicodeBlock::BlockElements => "Block Elements",
+            UnicodeBlock::GeometricShapes => "Geometric Shapes",
+            UnicodeBlock::MiscellaneousSymbols => "Miscellaneous Symbols",
+            UnicodeBlock::Dingbats => "Dingbats",
         }
     }
 }
 
+impl std::fmt::Display for UnicodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl From<Utf8> for char {
+    fn from(c: Utf8) -> char {
+        c.as_char()
+    }
+}
+
 impl Utf8 {
+    /// Returns the Unicode codepoint of the character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dekor::*;
+    ///
+    /// assert_eq!(Utf8::VPipeSlim.codepoint(), 0x2502);
+    /// assert_eq!(Utf8::VPipeSlim.as_char(), '│');
+    /// assert_eq!(Utf8::VPipeSlim.name(), "BOX DRAWINGS LIGHT VERTICAL");
+    /// assert_eq!(Utf8::VPipeSlim.block(), UnicodeBlock::BoxDrawing);
+    /// ```
+    ///
+    pub const fn codepoint(&self) -> u32 {
+        self.as_char() as u32
+    }
+
+    /// Binds the character to `charset`, ignoring the global setting made with `set_charset`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dekor::*;
+    ///
+    /// assert_eq!(Utf8::JointPipeBold.with_charset(Charset::Ascii).to_string(), "+");
+    /// assert_eq!(Utf8::RightwardsDoubleArrow.with_charset(Charset::Ascii).to_string(), "=>");
+    /// assert_eq!(Utf8::HPipeDouble.with_charset(Charset::Unicode).repeat(3), "═══");
+    /// ```
+    ///
+    pub const fn with_charset(self, charset: Charset) -> Glyph {
+        Glyph { character: self, charset }
+    }
+
+    /// Returns the Unicode block the character belongs to.
+    pub fn block(&self) -> UnicodeBlock {
+        UnicodeBlock::of(self.as_char()).expect("every `Utf8` character belongs to a known block")
+    }
+
+    /// Returns an iterator over every character in the enum, in declaration order.
+    pub fn iter() -> impl Iterator<Item = Utf8> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Finds the variant representing `c`.
+    ///
+    /// Some characters are available under more than one name (e.g. `Utf8::TriangleDown` and
+    /// `Utf8::CanadianSyllabicsE`), in which case the first declared variant is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dekor::*;
+    ///
+    /// assert_eq!(Utf8::from_char('┃'), Some(Utf8::VPipeBold));
+    /// assert_eq!(Utf8::from_char('ᐁ'), Some(Utf8::CanadianSyllabicsE));
+    /// assert_eq!(Utf8::from_char('a'), None);
+    /// ```
+    ///
+    pub fn from_char(c: char) -> Option<Utf8> {
+        Self::iter().find(|u| u.as_char() == c)
+    }
+
+    /// Finds the variant whose official Unicode name is `name`, ignoring ASCII case.
+    ///
+    /// As with `from_char`, the first declared variant is returned for shared characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dekor::*;
+    ///
+    /// assert_eq!(Utf8::from_name("BOX DRAWINGS LIGHT VERTICAL"), Some(Utf8::VPipeSlim));
+    /// assert_eq!(Utf8::from_name("heavy check mark"), Some(Utf8::HeavyCheckMark));
+    /// assert_eq!(Utf8::from_name("LATIN SMALL LETTER A"), None);
+    /// ```
+    ///
+    pub fn from_name(name: &str) -> Option<Utf8> {
+        let name = name.trim();
+        Self::iter().find(|u| u.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The lower eighth blocks from one eighth to a full block (▁▂▃▄▅▆▇█), indexed by `eighths - 1`.
+    pub const LOWER_EIGHTHS: [Utf8; 8] = [
+        Utf8::LowerOneEighthBlock,
+        Utf8::LowerOneQuarterBlock,
+        Utf8::LowerThreeEighthsBlock,
+        Utf8::LowerHalfBlock,
+        Utf8::LowerFiveEighthsBlock,
+        Utf8::LowerThreeQuartersBlock,
+        Utf8::LowerSevenEighthsBlock,
+        Utf8::FullBlock,
+    ];
+
+    /// The left eighth blocks from one eighth to a full block (▏▎▍▌▋▊▉█), indexed by `eighths - 1`.
+    pub const LEFT_EIGHTHS: [Utf8; 8] = [
+        Utf8::LeftOneEighthBlock,
+        Utf8::LeftOneQuarterBlock,
+        Utf8::LeftThreeEighthsBlock,
+        Utf8::LeftHalfBlock,
+        Utf8::LeftFiveEighthsBlock,
+        Utf8::LeftThreeQuartersBlock,
+        Utf8::LeftSevenEighthsBlock,
+        Utf8::FullBlock,
+    ];
+
+    /// The shades from lightest to a full block (░▒▓█).
+    pub const SHADES: [Utf8; 4] = [
+        Utf8::LightShade,
+        Utf8::MediumShade,
+        Utf8::DarkShade,
+        Utf8::FullBlock,
+    ];
+
+    /// Returns the lower block that best represents `fill`, a fraction between `0.0` and `1.0`.
+    ///
+    /// The fraction is rounded to the nearest eighth, values outside of the range are clamped.
+    /// Returns `None` when the fill rounds down to an empty cell. Intended for sparklines and
+    /// vertical meters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dekor::*;
+    ///
+    /// assert_eq!(Utf8::vertical_fill(0.5), Some(Utf8::LowerHalfBlock));
+    /// assert_eq!(Utf8::vertical_fill(1.0), Some(Utf8::FullBlock));
+    /// assert_eq!(Utf8::vertical_fill(0.01), None);
+    ///
+    /// let sparkline: String = [0.2, 0.5, 0.9, 0.4]
+    ///     .iter()
+    ///     .map(|v| Utf8::vertical_fill(*v).map_or(" ".to_string(), |c| c.to_string()))
+    ///     .collect();
+    /// assert_eq!(sparkline, "▂▄▇▃");
+    /// ```
+    ///
+    pub fn vertical_fill(fill: f64) -> Option<Utf8> {
+        Self::eighths(fill).map(|n| Self::LOWER_EIGHTHS[n - 1])
+    }
+
+    /// Returns the left block that best represents `fill`, a fraction between `0.0` and `1.0`.
+    ///
+    /// The fraction is rounded to the nearest eighth, values outside of the range are clamped.
+    /// Returns `None` when the fill rounds down to an empty cell. Intended for the partial cell
+    /// at the end of a horizontal progress bar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dekor::*;
+    ///
+    /// assert_eq!(Utf8::horizontal_fill(0.25), Some(Utf8::LeftOneQuarterBlock));
+    /// assert_eq!(Utf8::horizontal_fill(2.0), Some(Utf8::FullBlock));
+    /// assert_eq!(Utf8::horizontal_fill(0.0), None);
+    /// ```
+    ///
+    pub fn horizontal_fill(fill: f64) -> Option<Utf8> {
+        Self::eighths(fill).map(|n| Self::LEFT_EIGHTHS[n - 1])
+    }
+
+    /// Converts a fill fraction into a number of eighths in `1..=8`, or `None` if it rounds to zero.
+    fn eighths(fill: f64) -> Option<usize> {
+        if fill.is_nan() {
+            return None;
+        }
+        match (fill.clamp(0.0, 1.0) * 8.0).round() as usize {
+            0 => None,
+            n => Some(n),
+        }
+    }
+
     /// Repeats the character representation of the enum variant `n` times.
     ///
     /// # Arguments
@@ -114,6 +1308,42 @@ impl Utf8 {
     /// ```
     ///
     pub fn repeat(&self, n: usize) -> String {
-        std::iter::repeat(self.to_string()).take(n).collect()
+        self.to_string().repeat(n)
+    }
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_is_consistent() {
+        for c in Utf8::iter() {
+            assert_eq!(c.to_string(), c.as_char().to_string());
+            assert_eq!(char::from(c), c.as_char());
+            assert_eq!(UnicodeBlock::of(c.as_char()), Some(c.block()));
+            assert_eq!(Utf8::from_char(c.as_char()).map(|u| u.as_char()), Some(c.as_char()));
+            assert_eq!(Utf8::from_name(c.name()).map(|u| u.as_char()), Some(c.as_char()));
+        }
+    }
+
+    #[test]
+    fn test_ascii_fallback() {
+        for c in Utf8::iter() {
+            assert!(!c.ascii().is_empty() && c.ascii().is_ascii(), "{:?}", c);
+        }
+        assert_eq!(Charset::from_locale("en_US.UTF-8"), Charset::Unicode);
+        assert_eq!(Charset::from_locale("de_DE.utf8"), Charset::Unicode);
+        assert_eq!(Charset::from_locale("C"), Charset::Ascii);
+        assert_eq!(Charset::from_locale("en_US.ISO-8859-1"), Charset::Ascii);
+    }
+
+    #[test]
+    fn test_lookup_prefers_first_variant() {
+        assert_eq!(Utf8::from_char('—'), Some(Utf8::EndGuardArea));
+        assert_eq!(Utf8::from_name("CANADIAN SYLLABICS O"), Some(Utf8::CanadianSyllabicsO));
+        assert_eq!(Utf8::ALL.len(), Utf8::iter().count());
     }
 }
@@ -0,0 +1,230 @@
+use std::io::{self, Write};
+
+/// Cursor, line and screen control sequences.
+///
+/// Each variant implements `Display`, so it can be embedded in formatted output, and can be
+/// written to any `io::Write` with `write_to`. Rows and columns are 1-based, as in the terminal.
+/// Movements by zero cells produce no output, since most terminals treat a count of zero as one.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// // Rewrite the previous line
+/// let rewrite = format!("{}{}done", Control::PrevLine(1), Control::EraseLine);
+/// assert_eq!(rewrite, "\x1b[1F\x1b[2Kdone");
+///
+/// let mut out = Vec::new();
+/// Control::MoveTo { row: 3, column: 10 }.write_to(&mut out).unwrap();
+/// assert_eq!(out, b"\x1b[3;10H");
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Control {
+    /// Move the cursor up `n` rows
+    Up(u16),
+    /// Move the cursor down `n` rows
+    Down(u16),
+    /// Move the cursor right `n` columns
+    Forward(u16),
+    /// Move the cursor left `n` columns
+    Back(u16),
+    /// Move the cursor to the start of the line `n` rows down
+    NextLine(u16),
+    /// Move the cursor to the start of the line `n` rows up
+    PrevLine(u16),
+    /// Move the cursor to a column of the current row
+    Column(u16),
+    /// Move the cursor to a row and column
+    MoveTo { row: u16, column: u16 },
+    /// Save the cursor position and attributes
+    SavePosition,
+    /// Restore the cursor position and attributes saved with `SavePosition`
+    RestorePosition,
+    /// Hide the cursor
+    Hide,
+    /// Show the cursor
+    Show,
+    /// Erase the whole current line
+    EraseLine,
+    /// Erase from the cursor to the end of the line
+    EraseLineToEnd,
+    /// Erase from the start of the line to the cursor
+    EraseLineToStart,
+    /// Erase the whole screen
+    EraseScreen,
+    /// Erase from the cursor to the end of the screen
+    EraseBelow,
+    /// Erase from the start of the screen to the cursor
+    EraseAbove,
+    /// Scroll the contents of the scroll region up `n` rows
+    ScrollUp(u16),
+    /// Scroll the contents of the scroll region down `n` rows
+    ScrollDown(u16),
+    /// Restrict scrolling to the rows from `top` to `bottom`, inclusive
+    ScrollRegion { top: u16, bottom: u16 },
+    /// Restore scrolling to the whole screen
+    ResetScrollRegion,
+    /// Switch to the alternate screen buffer
+    EnterAlternateScreen,
+    /// Switch back to the main screen buffer
+    LeaveAlternateScreen,
+    /// Ask the terminal to mark pasted text with `ESC [ 200 ~` and `ESC [ 201 ~`
+    EnableBracketedPaste,
+    /// Stop marking pasted text
+    DisableBracketedPaste,
+}
+
+impl Control {
+    /// Writes the sequence to `writer`.
+    pub fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "{}", self)
+    }
+
+    /// Returns the control that undoes this one, if it changes terminal state that outlives it.
+    pub fn inverse(&self) -> Option<Control> {
+        match self {
+            Control::SavePosition => Some(Control::RestorePosition),
+            Control::Hide => Some(Control::Show),
+            Control::ScrollRegion { .. } => Some(Control::ResetScrollRegion),
+            Control::EnterAlternateScreen => Some(Control::LeaveAlternateScreen),
+            Control::EnableBracketedPaste => Some(Control::DisableBracketedPaste),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Control {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let movement = |f: &mut std::fmt::Formatter, n: u16, code: char| {
+            if n == 0 { Ok(()) } else { write!(f, "\x1b[{}{}", n, code) }
+        };
+        match *self {
+            Control::Up(n) => movement(f, n, 'A'),
+            Control::Down(n) => movement(f, n, 'B'),
+            Control::Forward(n) => movement(f, n, 'C'),
+            Control::Back(n) => movement(f, n, 'D'),
+            Control::NextLine(n) => movement(f, n, 'E'),
+            Control::PrevLine(n) => movement(f, n, 'F'),
+            Control::Column(column) => write!(f, "\x1b[{}G", column),
+            Control::MoveTo { row, column } => write!(f, "\x1b[{};{}H", row, column),
+            Control::SavePosition => write!(f, "\x1b7"),
+            Control::RestorePosition => write!(f, "\x1b8"),
+            Control::Hide => write!(f, "\x1b[?25l"),
+            Control::Show => write!(f, "\x1b[?25h"),
+            Control::EraseLine => write!(f, "\x1b[2K"),
+            Control::EraseLineToEnd => write!(f, "\x1b[K"),
+            Control::EraseLineToStart => write!(f, "\x1b[1K"),
+            Control::EraseScreen => write!(f, "\x1b[2J"),
+            Control::EraseBelow => write!(f, "\x1b[J"),
+            Control::EraseAbove => write!(f, "\x1b[1J"),
+            Control::ScrollUp(n) => movement(f, n, 'S'),
+            Control::ScrollDown(n) => movement(f, n, 'T'),
+            Control::ScrollRegion { top, bottom } => write!(f, "\x1b[{};{}r", top, bottom),
+            Control::ResetScrollRegion => write!(f, "\x1b[r"),
+            Control::EnterAlternateScreen => write!(f, "\x1b[?1049h"),
+            Control::LeaveAlternateScreen => write!(f, "\x1b[?1049l"),
+            Control::EnableBracketedPaste => write!(f, "\x1b[?2004h"),
+            Control::DisableBracketedPaste => write!(f, "\x1b[?2004l"),
+        }
+    }
+}
+
+/// Applies controls to a writer and undoes them, in reverse order, when dropped.
+///
+/// Hiding the cursor, entering the alternate screen, enabling bracketed paste, setting a scroll
+/// region and saving the cursor position are undone; other controls are written as is. The
+/// guard also implements `io::Write`, forwarding to the wrapped writer, so output can go through
+/// it while the controls are in effect.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+/// use std::io::Write;
+///
+/// let mut out = Vec::new();
+/// {
+///     let mut guard = ControlGuard::new(&mut out);
+///     guard.apply(Control::EnterAlternateScreen).unwrap();
+///     guard.apply(Control::Hide).unwrap();
+///     write!(guard, "fullscreen").unwrap();
+/// }
+/// assert_eq!(out, b"\x1b[?1049h\x1b[?25lfullscreen\x1b[?25h\x1b[?1049l");
+/// ```
+///
+pub struct ControlGuard<W: Write> {
+    writer: W,
+    restore: Vec<Control>,
+}
+
+impl<W: Write> ControlGuard<W> {
+    /// Wraps `writer` without applying anything yet.
+    pub fn new(writer: W) -> Self {
+        ControlGuard { writer, restore: Vec::new() }
+    }
+
+    /// Writes `control` and remembers how to undo it.
+    pub fn apply(&mut self, control: Control) -> io::Result<()> {
+        control.write_to(&mut self.writer)?;
+        self.writer.flush()?;
+        if let Some(inverse) = control.inverse() {
+            self.restore.push(inverse);
+        }
+        Ok(())
+    }
+
+    /// Undoes every applied control now instead of on drop.
+    pub fn restore(&mut self) -> io::Result<()> {
+        while let Some(control) = self.restore.pop() {
+            control.write_to(&mut self.writer)?;
+        }
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Write for ControlGuard<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Drop for ControlGuard<W> {
+    fn drop(&mut self) {
+        // Errors cannot be reported from `drop`
+        let _ = self.restore();
+    }
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequences() {
+        assert_eq!(Control::Up(3).to_string(), "\x1b[3A");
+        assert_eq!(Control::Up(0).to_string(), "");
+        assert_eq!(Control::ScrollRegion { top: 2, bottom: 20 }.to_string(), "\x1b[2;20r");
+        assert_eq!(Control::SavePosition.to_string(), "\x1b7");
+        assert_eq!(Control::DisableBracketedPaste.to_string(), "\x1b[?2004l");
+    }
+
+    #[test]
+    fn test_guard_restores_in_reverse() {
+        let mut out = Vec::new();
+        {
+            let mut guard = ControlGuard::new(&mut out);
+            guard.apply(Control::ScrollRegion { top: 1, bottom: 5 }).unwrap();
+            guard.apply(Control::EraseScreen).unwrap();
+            guard.apply(Control::EnableBracketedPaste).unwrap();
+        }
+        assert_eq!(out, b"\x1b[1;5r\x1b[2J\x1b[?2004h\x1b[?2004l\x1b[r");
+    }
+}
@@ -0,0 +1,521 @@
+use crate::characters::Utf8;
+use crate::style::{Color, Paint};
+use crate::text::{self, Align};
+
+/// One step of the edit script turning an old sequence into a new one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edit<T> {
+    /// An item present in both sequences
+    Equal(T),
+    /// An item only present in the old sequence
+    Delete(T),
+    /// An item only present in the new sequence
+    Insert(T),
+}
+
+impl<T> Edit<T> {
+    /// Returns the item of the edit.
+    pub fn value(&self) -> &T {
+        match self {
+            Edit::Equal(value) | Edit::Delete(value) | Edit::Insert(value) => value,
+        }
+    }
+}
+
+/// Computes a shortest edit script from `old` to `new` with Myers' algorithm.
+///
+/// Within each change, deletions come before insertions.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let edits = diff(&['a', 'b', 'c'], &['a', 'c', 'd']);
+/// assert_eq!(edits, [Edit::Equal('a'), Edit::Delete('b'), Edit::Equal('c'), Edit::Insert('d')]);
+/// ```
+///
+pub fn diff<T: PartialEq + Clone>(old: &[T], new: &[T]) -> Vec<Edit<T>> {
+    // Common ends are matched directly, which keeps the search to the changed middle
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..].iter().rev().zip(new[prefix..].iter().rev()).take_while(|(a, b)| a == b).count();
+
+    let mut edits: Vec<Edit<T>> = old[..prefix].iter().cloned().map(Edit::Equal).collect();
+    myers(&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix], &mut edits);
+    edits.extend(old[old.len() - suffix..].iter().cloned().map(Edit::Equal));
+    edits
+}
+
+fn myers<T: PartialEq + Clone>(a: &[T], b: &[T], edits: &mut Vec<Edit<T>>) {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = n + m;
+    if max == 0 {
+        return;
+    }
+
+    // `v[k + max]` is the furthest `x` reached on diagonal `k = x - y`; the diagonals in reach of
+    // each round are kept for backtracking
+    let mut v = vec![0isize; 2 * max as usize + 2];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+    let index = |k: isize| (k + max) as usize;
+    'search: for d in 0..=max {
+        trace.push(v[index(-d)..=index(d)].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[index(k - 1)] < v[index(k + 1)]) { v[index(k + 1)] } else { v[index(k - 1)] + 1 };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[index(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let (mut x, mut y) = (n, m);
+    let mut reversed = Vec::new();
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let at = |k: isize| v[(k + d) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) { k + 1 } else { k - 1 };
+        let prev_x = if d == 0 { 0 } else { at(prev_k) };
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            reversed.push(Edit::Equal(a[x as usize - 1].clone()));
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            if x == prev_x {
+                reversed.push(Edit::Insert(b[y as usize - 1].clone()));
+            } else {
+                reversed.push(Edit::Delete(a[x as usize - 1].clone()));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    edits.extend(reversed.into_iter().rev());
+}
+
+/// Diffs two texts line by line. Lines keep their line endings, so a missing final newline counts
+/// as a change.
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<Edit<&'a str>> {
+    diff(&old.split_inclusive('\n').collect::<Vec<_>>(), &new.split_inclusive('\n').collect::<Vec<_>>())
+}
+
+/// Diffs two texts word by word, where a word is a run of alphanumeric characters or
+/// underscores, a run of whitespace, or any other single character.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let edits = diff_words("let x = 1;", "let y = 1;");
+/// assert_eq!(edits[2..4], [Edit::Delete("x"), Edit::Insert("y")]);
+/// ```
+///
+pub fn diff_words<'a>(old: &'a str, new: &'a str) -> Vec<Edit<&'a str>> {
+    diff(&words(old), &words(new))
+}
+
+fn words(s: &str) -> Vec<&str> {
+    let class = |c: char| if c.is_alphanumeric() || c == '_' { 0 } else if c.is_whitespace() { 1 } else { 2 };
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        let next = chars.peek().map(|&(i, next)| (i, class(next)));
+        match next {
+            Some((_, next)) if next == class(c) && next != 2 => {},
+            Some((i, _)) => {
+                words.push(&s[start..i]);
+                start = i;
+            },
+            None => words.push(&s[start..]),
+        }
+    }
+    words
+}
+
+/// How a `Diff` is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Layout {
+    /// `-` and `+` lines one after the other, as in `diff -u`
+    #[default]
+    Unified,
+    /// The old text on the left and the new text on the right of a `Utf8::VPipeSlim` gutter
+    SideBySide,
+}
+
+/// A colored line diff of two texts, in unified or side-by-side layout.
+///
+/// Changes are grouped into hunks with `context` unchanged lines around them, each starting with
+/// an `@@ -line,count +line,count @@` header. Deleted lines are red, inserted lines green and
+/// context lines dimmed. Where a deleted line is replaced by an inserted one, the words that
+/// changed are highlighted with a background color.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let old = "name = \"dekor\"\nversion = \"0.2.1\"\nedition = \"2021\"\n";
+/// let new = "name = \"dekor\"\nversion = \"0.3.0\"\nedition = \"2021\"\n";
+///
+/// let unified = Diff::new(old, new).names("a/Cargo.toml", "b/Cargo.toml").context(1).color(false);
+/// assert_eq!(unified.to_string(), "\
+/// --- a/Cargo.toml
+/// +++ b/Cargo.toml
+/// @@ -1,3 +1,3 @@
+///  name = \"dekor\"
+/// -version = \"0.2.1\"
+/// +version = \"0.3.0\"
+///  edition = \"2021\"
+/// ");
+///
+/// let side_by_side = Diff::new(old, new).layout(Layout::SideBySide).context(0).width(47).color(false);
+/// assert_eq!(side_by_side.to_string(), "\
+/// @@ -2 +2 @@
+/// 2 -version = \"0.2.1\"   │ 2 +version = \"0.3.0\"
+/// ");
+///
+/// // Only the changed word is highlighted within the line
+/// let colored = Diff::new("a b c\n", "a x c\n").to_string();
+/// assert!(colored.contains("\x1b[31m-a \x1b[0m\x1b[31;48;5;52mb\x1b[0m\x1b[31m c\x1b[0m"));
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct Diff {
+    old: String,
+    new: String,
+    names: Option<(String, String)>,
+    layout: Layout,
+    context: usize,
+    width: usize,
+    intraline: bool,
+    color: bool,
+}
+
+impl Diff {
+    /// Creates a diff from `old` to `new`.
+    pub fn new<D: std::fmt::Display, E: std::fmt::Display>(old: D, new: E) -> Self {
+        Diff {
+            old: old.to_string(),
+            new: new.to_string(),
+            names: None,
+            layout: Layout::Unified,
+            context: 3,
+            width: 80,
+            intraline: true,
+            color: true,
+        }
+    }
+
+    /// Sets the names shown in the `---` and `+++` header lines, which are omitted otherwise.
+    pub fn names<D: std::fmt::Display, E: std::fmt::Display>(mut self, old: D, new: E) -> Self {
+        self.names = Some((old.to_string(), new.to_string()));
+        self
+    }
+
+    /// Sets the layout, `Layout::Unified` by default.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the number of unchanged lines shown around changes, 3 by default.
+    pub fn context(mut self, context: usize) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Sets the total width of the side-by-side layout, 80 columns by default. Longer lines are
+    /// truncated.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets whether changed words within replaced lines are highlighted, `true` by default.
+    pub fn intraline(mut self, intraline: bool) -> Self {
+        self.intraline = intraline;
+        self
+    }
+
+    /// Sets whether the diff is styled, `true` by default.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    fn paint(&self, paint: Paint) -> Paint {
+        if self.color { paint } else { Paint::new() }
+    }
+
+    /// Styles the `Delete` or `Insert` line `line`, highlighting the words not in `other`.
+    fn styled_line(&self, line: &str, other: Option<&str>, inserted: bool) -> String {
+        let base = self.paint(Paint::new().fg(if inserted { Color::Green } else { Color::Red }));
+        let highlight = self.paint(base.bg(Color::Fixed(if inserted { 22 } else { 52 })));
+        let words = match other.filter(|_| self.intraline) {
+            Some(other) if inserted => diff_words(other, line),
+            Some(other) => diff_words(line, other),
+            None => vec![Edit::Equal(line)],
+        };
+        // Lines without a single word in common are shown as wholly changed
+        let similar = words.iter().any(|w| matches!(w, Edit::Equal(s) if !s.trim().is_empty()));
+        let mut runs: Vec<(Paint, String)> = Vec::new();
+        for word in &words {
+            let (paint, word) = match (word, inserted) {
+                (Edit::Equal(s), _) => (base, *s),
+                (Edit::Delete(s), false) | (Edit::Insert(s), true) => (if similar { highlight } else { base }, *s),
+                _ => continue,
+            };
+            // Neighbouring words of the same style share one escape sequence
+            match runs.last_mut() {
+                Some((last, run)) if *last == paint => run.push_str(word),
+                _ => runs.push((paint, word.to_string())),
+            }
+        }
+        runs.iter().map(|(paint, run)| paint.paint(run).to_string()).collect()
+    }
+
+    fn unified(&self, hunk: &[Edit<&str>], f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let context = self.paint(Paint::new().fg(Color::Fixed(8)));
+        for (deleted, inserted) in blocks(hunk) {
+            if let [Edit::Equal(line)] = deleted {
+                writeln!(f, "{}", context.paint(&format!(" {}", line.trim_end_matches('\n'))))?;
+                // Like GNU `diff -u`, unchanged lines get the marker too
+                if !line.ends_with('\n') {
+                    writeln!(f, "\\ No newline at end of file")?;
+                }
+                continue;
+            }
+            // Each line is compared with the line at the same position on the other side
+            for (lines, others, sign) in [(deleted, inserted, "-"), (inserted, deleted, "+")] {
+                for (i, edit) in lines.iter().enumerate() {
+                    let line = edit.value();
+                    let other = others.get(i).map(|o| format!("{}{}", sign, o.value().trim_end_matches('\n')));
+                    let styled = self.styled_line(&format!("{}{}", sign, line.trim_end_matches('\n')), other.as_deref(), sign == "+");
+                    writeln!(f, "{}", styled)?;
+                    if !line.ends_with('\n') {
+                        writeln!(f, "\\ No newline at end of file")?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn side_by_side(&self, hunk: &[Edit<&str>], mut old_line: usize, mut new_line: usize, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let numbers = self.old.lines().count().max(self.new.lines().count()).to_string().len();
+        let side = self.width.saturating_sub(3) / 2;
+        let ellipsis = Utf8::HorizontalEllipsis.to_string();
+        let gutter = self.paint(Paint::new().fg(Color::Fixed(8))).paint(&format!(" {} ", Utf8::VPipeSlim)).to_string();
+        let context = self.paint(Paint::new().fg(Color::Fixed(8)));
+
+        for (deleted, inserted) in blocks(hunk) {
+            let cell = |edit: Option<&Edit<&str>>, other: Option<&Edit<&str>>, number: &mut usize, inserted: bool| {
+                let Some(edit) = edit else { return String::new() };
+                *number += 1;
+                let line = edit.value().trim_end_matches('\n').replace('\t', "    ");
+                let styled = match edit {
+                    Edit::Equal(_) => context.paint(&format!(" {}", line)).to_string(),
+                    _ => {
+                        let sign = if inserted { "+" } else { "-" };
+                        let other = other.map(|o| format!("{}{}", sign, o.value().trim_end_matches('\n').replace('\t', "    ")));
+                        self.styled_line(&format!("{}{}", sign, line), other.as_deref(), inserted)
+                    },
+                };
+                let cell = format!("{:>width$} {}", number, styled, width = numbers);
+                text::truncate(&cell, side, &ellipsis)
+            };
+            // As in the unified layout, any line without a final newline is followed by a marker
+            let marker = |edit: Option<&Edit<&str>>| match edit {
+                Some(edit) if !edit.value().ends_with('\n') => {
+                    let marker = format!("{:>width$} \\ No newline at end of file", "", width = numbers);
+                    text::truncate(&context.paint(&marker).to_string(), side, &ellipsis)
+                },
+                _ => String::new(),
+            };
+            // Unchanged lines are shown on both sides
+            let inserted = if inserted.is_empty() && matches!(deleted, [Edit::Equal(_)]) { deleted } else { inserted };
+            for i in 0..deleted.len().max(inserted.len()) {
+                let left = cell(deleted.get(i), inserted.get(i), &mut old_line, false);
+                let right = cell(inserted.get(i), deleted.get(i), &mut new_line, true);
+                writeln!(f, "{}{}{}", text::pad(&left, side, Align::Left), gutter, right)?;
+                let (left, right) = (marker(deleted.get(i)), marker(inserted.get(i)));
+                if !left.is_empty() || !right.is_empty() {
+                    writeln!(f, "{}{}{}", text::pad(&left, side, Align::Left), gutter, right)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Splits a hunk into unchanged lines, each alone on the deleted side, and changes made of the
+/// deleted lines and the inserted lines replacing them.
+#[allow(clippy::type_complexity)]
+fn blocks<'h, 'a>(hunk: &'h [Edit<&'a str>]) -> Vec<(&'h [Edit<&'a str>], &'h [Edit<&'a str>])> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < hunk.len() {
+        if let Edit::Equal(_) = hunk[i] {
+            blocks.push((&hunk[i..=i], &hunk[..0]));
+            i += 1;
+            continue;
+        }
+        let deleted = hunk[i..].iter().take_while(|e| matches!(e, Edit::Delete(_))).count();
+        let inserted = hunk[i + deleted..].iter().take_while(|e| matches!(e, Edit::Insert(_))).count();
+        blocks.push((&hunk[i..i + deleted], &hunk[i + deleted..i + deleted + inserted]));
+        i += deleted + inserted;
+    }
+    blocks
+}
+
+/// Formats the range of a hunk header, e.g. `3,4` or `3` for a single line.
+fn range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, count),
+    }
+}
+
+impl std::fmt::Display for Diff {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let edits = diff_lines(&self.old, &self.new);
+        let changes: Vec<usize> = (0..edits.len()).filter(|&i| !matches!(edits[i], Edit::Equal(_))).collect();
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let bold = self.paint(Paint::new().bold());
+        if let Some((old, new)) = &self.names {
+            match self.layout {
+                Layout::Unified => writeln!(f, "{}\n{}", bold.paint(&format!("--- {}", old)), bold.paint(&format!("+++ {}", new)))?,
+                Layout::SideBySide => {
+                    let side = self.width.saturating_sub(3) / 2;
+                    let old = text::pad(&bold.paint(old).to_string(), side, Align::Left);
+                    writeln!(f, "{} {} {}", old, Utf8::VPipeSlim, bold.paint(new))?;
+                },
+            }
+        }
+
+        // Changes closer than twice the context share a hunk
+        let mut start = 0;
+        while start < changes.len() {
+            let mut end = start;
+            while end + 1 < changes.len() && changes[end + 1] - changes[end] <= 2 * self.context + 1 {
+                end += 1;
+            }
+            let first = changes[start].saturating_sub(self.context);
+            let last = (changes[end] + self.context + 1).min(edits.len());
+            let hunk = &edits[first..last];
+
+            let old_start = edits[..first].iter().filter(|e| !matches!(e, Edit::Insert(_))).count();
+            let new_start = edits[..first].iter().filter(|e| !matches!(e, Edit::Delete(_))).count();
+            let old_count = hunk.iter().filter(|e| !matches!(e, Edit::Insert(_))).count();
+            let new_count = hunk.iter().filter(|e| !matches!(e, Edit::Delete(_))).count();
+            let header = format!("@@ -{} +{} @@", range(old_start, old_count), range(new_start, new_count));
+            writeln!(f, "{}", self.paint(Paint::new().fg(Color::Cyan)).paint(&header))?;
+
+            match self.layout {
+                Layout::Unified => self.unified(hunk, f)?,
+                Layout::SideBySide => self.side_by_side(hunk, old_start, new_start, f)?,
+            }
+            start = end + 1;
+        }
+        Ok(())
+    }
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Applies an edit script to check it reproduces both sequences.
+    fn check(old: &str, new: &str) -> usize {
+        let (old, new): (Vec<char>, Vec<char>) = (old.chars().collect(), new.chars().collect());
+        let edits = diff(&old, &new);
+        let kept = |skip: fn(&Edit<char>) -> bool| edits.iter().filter(|e| !skip(e)).map(|e| *e.value()).collect::<Vec<_>>();
+        assert_eq!(kept(|e| matches!(e, Edit::Insert(_))), old);
+        assert_eq!(kept(|e| matches!(e, Edit::Delete(_))), new);
+        edits.iter().filter(|e| !matches!(e, Edit::Equal(_))).count()
+    }
+
+    #[test]
+    fn test_shortest_edit_script() {
+        assert_eq!(check("ABCABBA", "CBABAC"), 5);
+        assert_eq!(check("", "abc"), 3);
+        assert_eq!(check("abc", ""), 3);
+        assert_eq!(check("same", "same"), 0);
+        assert_eq!(check("kitten", "sitting"), 5);
+        assert_eq!(words("a_b  c+=d"), ["a_b", "  ", "c", "+", "=", "d"]);
+    }
+
+    #[test]
+    fn test_hunks_and_missing_newline() {
+        let old: String = (1..=12).map(|i| format!("{}\n", i)).collect();
+        let new: String = (1..=12).map(|i| match i {
+            2 => "two\n".to_string(),
+            12 => "12".to_string(),
+            _ => format!("{}\n", i),
+        }).collect();
+        let out = Diff::new(&old, &new).context(1).color(false).to_string();
+        assert_eq!(out, "\
+@@ -1,3 +1,3 @@
+ 1
+-2
++two
+ 3
+@@ -11,2 +11,2 @@
+ 11
+-12
++12
+\\ No newline at end of file
+");
+        assert_eq!(Diff::new("a\n", "").color(false).to_string(), "@@ -1 +0,0 @@\n-a\n");
+        assert_eq!(Diff::new("same", "same").to_string(), "");
+    }
+
+    #[test]
+    fn test_side_by_side_unpaired_and_truncated() {
+        let diff = Diff::new("keep\nold line that is long\n", "keep\nnew\nadded\n").layout(Layout::SideBySide).width(27).color(false);
+        assert_eq!(diff.to_string(), "\
+@@ -1,2 +1,3 @@
+1  keep      │ 1  keep
+2 -old line… │ 2 +new
+             │ 3 +added
+");
+    }
+
+    #[test]
+    fn test_side_by_side_missing_newline() {
+        let diff = Diff::new("a\nb\n", "a\nc").layout(Layout::SideBySide).width(61).color(false);
+        assert_eq!(diff.to_string(), "\
+@@ -1,2 +1,2 @@
+1  a                          │ 1  a
+2 -b                          │ 2 +c
+                              │   \\ No newline at end of file
+");
+        let diff = Diff::new("b", "c\n").layout(Layout::SideBySide).width(27).color(false);
+        assert_eq!(diff.to_string(), "@@ -1 +1 @@\n1 -b         │ 1 +c\n  \\ No newl… │ \n");
+        let both = Diff::new("a\nb", "c\nb").color(false);
+        assert_eq!(both.to_string(), "@@ -1,2 +1,2 @@\n-a\n+c\n b\n\\ No newline at end of file\n");
+        let diff = both.layout(Layout::SideBySide).width(27);
+        assert_eq!(diff.to_string(), "\
+@@ -1,2 +1,2 @@
+1 -a         │ 1 +c
+2  b         │ 2  b
+  \\ No newl… │   \\ No newl…
+");
+    }
+}
@@ -0,0 +1,428 @@
+use crate::characters::Utf8;
+use crate::pretty::Theme;
+use crate::style::Paint;
+use crate::text;
+
+/// The deepest nesting of arrays and objects accepted before parsing gives up.
+const MAX_NESTING: usize = 128;
+
+/// Characters of context shown on each side of a parse error on long lines.
+const ERROR_CONTEXT: usize = 30;
+
+/// Pretty-prints JSON text with syntax highlighting, without any dependencies.
+///
+/// Keys, strings, numbers, booleans and `null` are styled by the `Theme`. Strings and numbers are
+/// printed exactly as written in the input, escapes included. By default objects and arrays are
+/// printed with one member per line and 2 spaces of indentation; `compact` removes all
+/// whitespace instead, and `guides` draws a `Utf8::VPipeSlim` line down each indentation level.
+///
+/// Invalid input is reported as a `JsonError` showing the line with a caret under the offending
+/// column.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let json = r#"{"id": 7, "tags": ["a", "b"], "owner": {"name": "Ada", "admin": true}, "parent": null}"#;
+///
+/// let pretty = PrettyJson::new().theme(Theme::plain()).guides(true);
+/// assert_eq!(pretty.render(json).unwrap(), r#"{
+/// │ "id": 7,
+/// │ "tags": [
+/// │ │ "a",
+/// │ │ "b"
+/// │ ],
+/// │ "owner": {
+/// │ │ "name": "Ada",
+/// │ │ "admin": true
+/// │ },
+/// │ "parent": null
+/// }"#);
+///
+/// let compact = PrettyJson::new().theme(Theme::plain()).compact(true);
+/// assert_eq!(compact.render("[1, 2.5e3, {}]").unwrap(), "[1,2.5e3,{}]");
+///
+/// let error = compact.render(r#"{"id": 7 "tags": []}"#).unwrap_err();
+/// assert_eq!((error.line(), error.column()), (1, 10));
+/// assert_eq!(error.to_string(), r#"expected `,` or `}`, found `"` at line 1, column 10
+/// {"id": 7 "tags": []}
+///          ^"#);
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrettyJson {
+    theme: Theme,
+    indent: usize,
+    compact: bool,
+    guides: bool,
+}
+
+impl Default for PrettyJson {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrettyJson {
+    /// Creates a printer with the default theme and 2 spaces of indentation.
+    pub fn new() -> Self {
+        PrettyJson { theme: Theme::default(), indent: 2, compact: false, guides: false }
+    }
+
+    /// Sets the styles, `Theme::default()` by default. Parse errors use `Theme::error`.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets the number of columns per indentation level, 2 by default.
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets whether the output is printed on a single line without whitespace.
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Sets whether each indentation level starts with a vertical guide line, styled with
+    /// `Theme::guide`. Has no effect in compact mode.
+    pub fn guides(mut self, guides: bool) -> Self {
+        self.guides = guides;
+        self
+    }
+
+    /// Parses `json` and returns it pretty-printed.
+    pub fn render(&self, json: &str) -> Result<String, JsonError> {
+        let value = Parser { src: json, pos: 0, paint: self.theme.error }.document()?;
+        let mut out = String::with_capacity(json.len() * 2);
+        self.value(&value, 0, &mut out);
+        Ok(out)
+    }
+
+    fn value(&self, value: &Value, level: usize, out: &mut String) {
+        let theme = &self.theme;
+        let (open, close, members) = match value {
+            Value::String(s) => return push(out, s, theme.string),
+            Value::Number(n) => return push(out, n, theme.number),
+            Value::Boolean(b) => return push(out, b, theme.boolean),
+            Value::Null => return push(out, "null", theme.null),
+            Value::Array(members) => ("[", "]", members),
+            Value::Object(members) => ("{", "}", members),
+        };
+
+        push(out, open, theme.punctuation);
+        if !members.is_empty() {
+            for (i, (key, member)) in members.iter().enumerate() {
+                if i > 0 {
+                    push(out, ",", theme.punctuation);
+                }
+                self.newline(level + 1, out);
+                if let Some(key) = key {
+                    push(out, key, theme.key);
+                    push(out, ":", theme.punctuation);
+                    if !self.compact {
+                        out.push(' ');
+                    }
+                }
+                self.value(member, level + 1, out);
+            }
+            self.newline(level, out);
+        }
+        push(out, close, theme.punctuation);
+    }
+
+    fn newline(&self, level: usize, out: &mut String) {
+        if self.compact {
+            return;
+        }
+        out.push('\n');
+        if self.guides {
+            let guide = self.theme.guide.paint(&Utf8::VPipeSlim).to_string();
+            for _ in 0..level {
+                out.push_str(&guide);
+                out.push_str(&" ".repeat(self.indent.saturating_sub(1)));
+            }
+        } else {
+            out.push_str(&" ".repeat(level * self.indent));
+        }
+    }
+}
+
+fn push(out: &mut String, text: &str, paint: Paint) {
+    out.push_str(&paint.paint(text).to_string());
+}
+
+/// A parsed JSON value, borrowing strings and numbers from the input.
+enum Value<'a> {
+    String(&'a str),
+    Number(&'a str),
+    Boolean(&'a str),
+    Null,
+    Array(Vec<(Option<&'a str>, Value<'a>)>),
+    Object(Vec<(Option<&'a str>, Value<'a>)>),
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    paint: Paint,
+}
+
+impl<'a> Parser<'a> {
+    fn document(&mut self) -> Result<Value<'a>, JsonError> {
+        let value = self.value(0)?;
+        self.skip_whitespace();
+        if self.pos < self.src.len() {
+            return Err(self.error(format!("unexpected {} after the value", self.found())));
+        }
+        Ok(value)
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Value<'a>, JsonError> {
+        self.skip_whitespace();
+        let rest = &self.src[self.pos..];
+        match rest.chars().next() {
+            Some('{') | Some('[') if depth >= MAX_NESTING => Err(self.error(format!("nesting deeper than {} levels", MAX_NESTING))),
+            Some('{') => self.container(depth, true),
+            Some('[') => self.container(depth, false),
+            Some('"') => self.string().map(Value::String),
+            Some('-' | '0'..='9') => self.number(),
+            _ => {
+                for (literal, value) in [("true", Value::Boolean("true")), ("false", Value::Boolean("false")), ("null", Value::Null)] {
+                    if rest.starts_with(literal) {
+                        self.pos += literal.len();
+                        return Ok(value);
+                    }
+                }
+                Err(self.error(format!("expected a value, found {}", self.found())))
+            },
+        }
+    }
+
+    fn container(&mut self, depth: usize, object: bool) -> Result<Value<'a>, JsonError> {
+        let close = if object { '}' } else { ']' };
+        self.pos += 1;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.eat(close) {
+            return Ok(if object { Value::Object(members) } else { Value::Array(members) });
+        }
+        loop {
+            let key = if object {
+                self.skip_whitespace();
+                if !self.src[self.pos..].starts_with('"') {
+                    return Err(self.error(format!("expected a string key, found {}", self.found())));
+                }
+                let key = self.string()?;
+                self.skip_whitespace();
+                if !self.eat(':') {
+                    return Err(self.error(format!("expected `:` after the key, found {}", self.found())));
+                }
+                Some(key)
+            } else {
+                None
+            };
+            members.push((key, self.value(depth + 1)?));
+            self.skip_whitespace();
+            if self.eat(close) {
+                break;
+            }
+            if !self.eat(',') {
+                return Err(self.error(format!("expected `,` or `{}`, found {}", close, self.found())));
+            }
+        }
+        Ok(if object { Value::Object(members) } else { Value::Array(members) })
+    }
+
+    /// Parses a string, returning it with its quotes and escapes.
+    fn string(&mut self) -> Result<&'a str, JsonError> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut chars = self.src[self.pos..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(&self.src[start..self.pos]);
+                },
+                '\\' => {
+                    let valid = match chars.next() {
+                        Some((_, '"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't')) => true,
+                        Some((_, 'u')) => (0..4).all(|_| chars.next().is_some_and(|(_, c)| c.is_ascii_hexdigit())),
+                        _ => false,
+                    };
+                    if !valid {
+                        self.pos += i;
+                        return Err(self.error("invalid escape sequence in string".to_string()));
+                    }
+                },
+                c if c < ' ' => {
+                    self.pos += i;
+                    return Err(self.error("control character in string, it must be escaped".to_string()));
+                },
+                _ => {},
+            }
+        }
+        self.pos = start;
+        Err(self.error("unterminated string".to_string()))
+    }
+
+    /// Parses `-? (0 | [1-9][0-9]*) (. [0-9]+)? ([eE] [+-]? [0-9]+)?`.
+    fn number(&mut self) -> Result<Value<'a>, JsonError> {
+        let start = self.pos;
+        let digits = |parser: &mut Self| {
+            let len = parser.src[parser.pos..].find(|c: char| !c.is_ascii_digit()).unwrap_or(parser.src.len() - parser.pos);
+            parser.pos += len;
+            len
+        };
+        self.eat('-');
+        if !self.eat('0') && digits(self) == 0 {
+            return Err(self.error(format!("expected a digit, found {}", self.found())));
+        }
+        if self.eat('.') && digits(self) == 0 {
+            return Err(self.error(format!("expected a digit after the decimal point, found {}", self.found())));
+        }
+        if self.eat('e') || self.eat('E') {
+            let _ = self.eat('+') || self.eat('-');
+            if digits(self) == 0 {
+                return Err(self.error(format!("expected a digit in the exponent, found {}", self.found())));
+            }
+        }
+        Ok(Value::Number(&self.src[start..self.pos]))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.src[self.pos..];
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.src[self.pos..].starts_with(c);
+        if found {
+            self.pos += c.len_utf8();
+        }
+        found
+    }
+
+    /// Describes the character at the current position for error messages.
+    fn found(&self) -> String {
+        match self.src[self.pos..].chars().next() {
+            Some(c) if c.is_control() => format!("{:?}", c),
+            Some(c) => format!("`{}`", c),
+            None => "end of input".to_string(),
+        }
+    }
+
+    fn error(&self, message: String) -> JsonError {
+        let line_start = self.src[..self.pos].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.src[self.pos..].find(['\r', '\n']).map_or(self.src.len(), |i| self.pos + i);
+        JsonError {
+            message,
+            line: self.src[..line_start].matches('\n').count() + 1,
+            column: self.src[line_start..self.pos].chars().count() + 1,
+            source: self.src[line_start..line_end].to_string(),
+            paint: self.paint,
+        }
+    }
+}
+
+/// A JSON parse error, displayed with the offending line and a caret under the column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonError {
+    message: String,
+    line: usize,
+    column: usize,
+    source: String,
+    paint: Paint,
+}
+
+impl JsonError {
+    /// Returns the description of the error, without its position.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the 1-based line of the error.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the 1-based column of the error, counted in characters.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl std::fmt::Display for JsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let position = format!("{} at line {}, column {}", self.message, self.line, self.column);
+        writeln!(f, "{}", self.paint.paint(&position))?;
+
+        // Long lines, e.g. of minified JSON, are cut down to the context around the column
+        let chars: Vec<char> = self.source.chars().collect();
+        let column = self.column - 1;
+        let start = column.saturating_sub(ERROR_CONTEXT);
+        let end = chars.len().min(column + ERROR_CONTEXT);
+        let ellipsis = Utf8::HorizontalEllipsis.to_string();
+        let (before, after) = (if start > 0 { ellipsis.as_str() } else { "" }, if end < chars.len() { ellipsis.as_str() } else { "" });
+        let shown: String = chars[start..end].iter().collect();
+        writeln!(f, "{}{}{}", before, shown, after)?;
+
+        // Tabs are kept so the caret lines up however wide they are displayed
+        let mut indent = " ".repeat(text::display_width(before));
+        for &c in &chars[start..column] {
+            match c {
+                '\t' => indent.push('\t'),
+                c => indent.push_str(&" ".repeat(text::char_width(c))),
+            }
+        }
+        write!(f, "{}{}", indent, self.paint.paint("^"))
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(json: &str) -> JsonError {
+        PrettyJson::new().render(json).unwrap_err()
+    }
+
+    #[test]
+    fn test_styles_and_indent() {
+        let theme = Theme { key: Paint::new().bold(), number: Paint::new().underline(), ..Theme::plain() };
+        let out = PrettyJson::new().theme(theme).indent(4).render(" {\"a\" : [ -0.5E+2 , [] ] } ").unwrap();
+        assert_eq!(out, "{\n    \x1b[1m\"a\"\x1b[0m: [\n        \x1b[4m-0.5E+2\x1b[0m,\n        []\n    ]\n}");
+        assert_eq!(PrettyJson::new().render(r#""tab\té""#).unwrap(), "\x1b[32m\"tab\\té\"\x1b[0m");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(error("").message(), "expected a value, found end of input");
+        assert_eq!(error("[1,]").message(), "expected a value, found `]`");
+        assert_eq!(error("{1: 2}").message(), "expected a string key, found `1`");
+        assert_eq!(error("01").message(), "unexpected `1` after the value");
+        assert_eq!(error("1.e5").message(), "expected a digit after the decimal point, found `e`");
+        assert_eq!(error("\"a\\x\"").message(), "invalid escape sequence in string");
+        assert_eq!(error("\"a\nb\"").message(), "control character in string, it must be escaped");
+        assert_eq!(error(&"[".repeat(200)).message(), "nesting deeper than 128 levels");
+
+        let multiline = error("{\n\t\"日本\": nul\n}");
+        assert_eq!((multiline.line(), multiline.column()), (2, 8));
+        let plain = text::strip_ansi(&multiline.to_string());
+        assert_eq!(plain, "expected a value, found `n` at line 2, column 8\n\t\"日本\": nul\n\t        ^");
+
+        let long = error(&format!("[{}x]", "1, ".repeat(40)));
+        let plain = text::strip_ansi(&long.to_string());
+        assert_eq!(plain.lines().nth(1).unwrap(), format!("…{}x]", "1, ".repeat(10)));
+        assert!(plain.ends_with(&format!("{}^", " ".repeat(31))));
+    }
+}
@@ -0,0 +1,321 @@
+/// The weight of a single edge of a box-drawing cell.
+///
+/// Weights are ordered `None < Light < Heavy < Double`; when two junctions are merged the
+/// heavier weight of each edge wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Weight {
+    /// No line on this edge
+    #[default]
+    None,
+    /// Light (slim) line, e.g. `│`
+    Light,
+    /// Heavy (bold) line, e.g. `┃`
+    Heavy,
+    /// Double line, e.g. `║`
+    Double,
+}
+
+/// A box-drawing cell described by the weight of the line on each of its four edges.
+///
+/// A `Junction` can be converted to the matching glyph from the Unicode Box Drawing block
+/// (U+2500 - U+257F) and back, which allows canvases to merge overlapping lines into the
+/// correct character instead of overwriting one with the other.
+///
+/// The `arc` flag requests the rounded variant of a light corner (`╭ ╮ ╯ ╰`) and is ignored
+/// for every other shape.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let corner = Junction::new(Weight::None, Weight::Light, Weight::Light, Weight::None);
+/// let left = Junction::new(Weight::None, Weight::None, Weight::None, Weight::Heavy);
+/// assert_eq!(corner.to_char(), Some('┌'));
+/// assert_eq!((corner | left).to_char(), Some('┭'));
+///
+/// let tee = Junction::from_char('╦').unwrap();
+/// assert_eq!(tee.left, Weight::Double);
+/// assert_eq!(tee.up, Weight::None);
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Junction {
+    pub up: Weight,
+    pub right: Weight,
+    pub down: Weight,
+    pub left: Weight,
+    pub arc: bool,
+}
+
+const O: Weight = Weight::None;
+const L: Weight = Weight::Light;
+const H: Weight = Weight::Heavy;
+const D: Weight = Weight::Double;
+
+/// Box-drawing glyphs with their edges in `up, right, down, left` order.
+/// Solid lines precede their dashed counterparts so lookups by edges yield the solid glyph.
+const GLYPHS: &[(char, Weight, Weight, Weight, Weight)] = &[
+    ('─', O, L, O, L),
+    ('━', O, H, O, H),
+    ('│', L, O, L, O),
+    ('┃', H, O, H, O),
+    ('┄', O, L, O, L),
+    ('┅', O, H, O, H),
+    ('┆', L, O, L, O),
+    ('┇', H, O, H, O),
+    ('┈', O, L, O, L),
+    ('┉', O, H, O, H),
+    ('┊', L, O, L, O),
+    ('┋', H, O, H, O),
+    ('┌', O, L, L, O),
+    ('┍', O, H, L, O),
+    ('┎', O, L, H, O),
+    ('┏', O, H, H, O),
+    ('┐', O, O, L, L),
+    ('┑', O, O, L, H),
+    ('┒', O, O, H, L),
+    ('┓', O, O, H, H),
+    ('└', L, L, O, O),
+    ('┕', L, H, O, O),
+    ('┖', H, L, O, O),
+    ('┗', H, H, O, O),
+    ('┘', L, O, O, L),
+    ('┙', L, O, O, H),
+    ('┚', H, O, O, L),
+    ('┛', H, O, O, H),
+    ('├', L, L, L, O),
+    ('┝', L, H, L, O),
+    ('┞', H, L, L, O),
+    ('┟', L, L, H, O),
+    ('┠', H, L, H, O),
+    ('┡', H, H, L, O),
+    ('┢', L, H, H, O),
+    ('┣', H, H, H, O),
+    ('┤', L, O, L, L),
+    ('┥', L, O, L, H),
+    ('┦', H, O, L, L),
+    ('┧', L, O, H, L),
+    ('┨', H, O, H, L),
+    ('┩', H, O, L, H),
+    ('┪', L, O, H, H),
+    ('┫', H, O, H, H),
+    ('┬', O, L, L, L),
+    ('┭', O, L, L, H),
+    ('┮', O, H, L, L),
+    ('┯', O, H, L, H),
+    ('┰', O, L, H, L),
+    ('┱', O, L, H, H),
+    ('┲', O, H, H, L),
+    ('┳', O, H, H, H),
+    ('┴', L, L, O, L),
+    ('┵', L, L, O, H),
+    ('┶', L, H, O, L),
+    ('┷', L, H, O, H),
+    ('┸', H, L, O, L),
+    ('┹', H, L, O, H),
+    ('┺', H, H, O, L),
+    ('┻', H, H, O, H),
+    ('┼', L, L, L, L),
+    ('┽', L, L, L, H),
+    ('┾', L, H, L, L),
+    ('┿', L, H, L, H),
+    ('╀', H, L, L, L),
+    ('╁', L, L, H, L),
+    ('╂', H, L, H, L),
+    ('╃', H, L, L, H),
+    ('╄', H, H, L, L),
+    ('╅', L, L, H, H),
+    ('╆', L, H, H, L),
+    ('╇', H, H, L, H),
+    ('╈', L, H, H, H),
+    ('╉', H, L, H, H),
+    ('╊', H, H, H, L),
+    ('╋', H, H, H, H),
+    ('╌', O, L, O, L),
+    ('╍', O, H, O, H),
+    ('╎', L, O, L, O),
+    ('╏', H, O, H, O),
+    ('═', O, D, O, D),
+    ('║', D, O, D, O),
+    ('╒', O, D, L, O),
+    ('╓', O, L, D, O),
+    ('╔', O, D, D, O),
+    ('╕', O, O, L, D),
+    ('╖', O, O, D, L),
+    ('╗', O, O, D, D),
+    ('╘', L, D, O, O),
+    ('╙', D, L, O, O),
+    ('╚', D, D, O, O),
+    ('╛', L, O, O, D),
+    ('╜', D, O, O, L),
+    ('╝', D, O, O, D),
+    ('╞', L, D, L, O),
+    ('╟', D, L, D, O),
+    ('╠', D, D, D, O),
+    ('╡', L, O, L, D),
+    ('╢', D, O, D, L),
+    ('╣', D, O, D, D),
+    ('╤', O, D, L, D),
+    ('╥', O, L, D, L),
+    ('╦', O, D, D, D),
+    ('╧', L, D, O, D),
+    ('╨', D, L, O, L),
+    ('╩', D, D, O, D),
+    ('╪', L, D, L, D),
+    ('╫', D, L, D, L),
+    ('╬', D, D, D, D),
+    ('╴', O, O, O, L),
+    ('╵', L, O, O, O),
+    ('╶', O, L, O, O),
+    ('╷', O, O, L, O),
+    ('╸', O, O, O, H),
+    ('╹', H, O, O, O),
+    ('╺', O, H, O, O),
+    ('╻', O, O, H, O),
+    ('╼', O, H, O, L),
+    ('╽', L, O, H, O),
+    ('╾', O, L, O, H),
+    ('╿', H, O, L, O),
+];
+
+/// Rounded light corners, selected when `Junction::arc` is set.
+const ARCS: &[(char, Weight, Weight, Weight, Weight)] = &[
+    ('╭', O, L, L, O),
+    ('╮', O, O, L, L),
+    ('╯', L, O, O, L),
+    ('╰', L, L, O, O),
+];
+
+impl Junction {
+    /// A cell without any lines.
+    pub const EMPTY: Junction = Junction { up: O, right: O, down: O, left: O, arc: false };
+
+    /// Creates a junction from the weights of its `up`, `right`, `down` and `left` edges.
+    pub const fn new(up: Weight, right: Weight, down: Weight, left: Weight) -> Self {
+        Junction { up, right, down, left, arc: false }
+    }
+
+    /// Returns the same junction with the rounded corner variant requested.
+    pub const fn arc(self) -> Self {
+        Junction { arc: true, ..self }
+    }
+
+    /// Returns `true` if none of the edges carry a line.
+    pub fn is_empty(&self) -> bool {
+        self.up == O && self.right == O && self.down == O && self.left == O
+    }
+
+    /// Combines two junctions edge by edge, keeping the heavier weight of each edge.
+    ///
+    /// The result keeps the rounded corner preference if either side had it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dekor::*;
+    ///
+    /// let vertical = Junction::from_char('│').unwrap();
+    /// let horizontal = Junction::from_char('━').unwrap();
+    /// assert_eq!(vertical.merge(horizontal).to_char(), Some('┿'));
+    /// ```
+    ///
+    pub fn merge(self, other: Junction) -> Junction {
+        Junction {
+            up: self.up.max(other.up),
+            right: self.right.max(other.right),
+            down: self.down.max(other.down),
+            left: self.left.max(other.left),
+            arc: self.arc || other.arc,
+        }
+    }
+
+    /// Returns the box-drawing glyph for this junction.
+    ///
+    /// Returns `None` for an empty cell and for combinations Unicode has no glyph for, such as
+    /// heavy and double lines meeting in the same cell.
+    pub fn to_char(&self) -> Option<char> {
+        let edges = (self.up, self.right, self.down, self.left);
+        let find = |table: &[(char, Weight, Weight, Weight, Weight)]| {
+            table.iter()
+                .find(|&&(_, u, r, d, l)| (u, r, d, l) == edges)
+                .map(|&(c, ..)| c)
+        };
+
+        if self.arc {
+            if let Some(c) = find(ARCS) {
+                return Some(c);
+            }
+        }
+        find(GLYPHS)
+    }
+
+    /// Returns the junction a box-drawing glyph represents.
+    ///
+    /// Dashed lines map to their solid equivalents and rounded corners set `arc`. Returns `None`
+    /// for characters outside of the box-drawing block and for the diagonal lines.
+    pub fn from_char(c: char) -> Option<Junction> {
+        if let Some(&(_, up, right, down, left)) = ARCS.iter().find(|g| g.0 == c) {
+            return Some(Junction::new(up, right, down, left).arc());
+        }
+        GLYPHS.iter()
+            .find(|g| g.0 == c)
+            .map(|&(_, up, right, down, left)| Junction::new(up, right, down, left))
+    }
+}
+
+impl std::ops::BitOr for Junction {
+    type Output = Junction;
+
+    fn bitor(self, rhs: Junction) -> Junction {
+        self.merge(rhs)
+    }
+}
+
+impl std::ops::BitOrAssign for Junction {
+    fn bitor_assign(&mut self, rhs: Junction) {
+        *self = self.merge(rhs);
+    }
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for &(c, ..) in GLYPHS.iter().chain(ARCS) {
+            let junction = Junction::from_char(c).unwrap();
+            let back = junction.to_char().unwrap();
+            // Dashed glyphs come back as their solid equivalent
+            assert_eq!(Junction::from_char(back), Some(junction));
+        }
+        assert_eq!(Junction::from_char('╭').unwrap().to_char(), Some('╭'));
+        assert_eq!(Junction::from_char('┄').unwrap().to_char(), Some('─'));
+    }
+
+    #[test]
+    fn test_merge() {
+        let down_right = Junction::new(O, L, L, O);
+        let left_heavy = Junction::new(O, O, O, H);
+        assert_eq!((down_right | left_heavy).to_char(), Some('┭'));
+
+        let mut cross = Junction::from_char('═').unwrap();
+        cross |= Junction::from_char('│').unwrap();
+        assert_eq!(cross.to_char(), Some('╪'));
+
+        // A rounded corner that gains a third edge becomes a regular tee
+        let tee = Junction::from_char('╰').unwrap() | Junction::from_char('╷').unwrap();
+        assert_eq!(tee.to_char(), Some('├'));
+    }
+
+    #[test]
+    fn test_unrepresentable() {
+        assert_eq!(Junction::EMPTY.to_char(), None);
+        assert_eq!(Junction::new(H, D, O, O).to_char(), None);
+        assert_eq!(Junction::from_char('a'), None);
+        assert_eq!(Junction::from_char('╱'), None);
+    }
+}
@@ -14,13 +14,35 @@
 //!   - Intention is to complete the list of characters over time
 //!   - Character list source: <https://www.fileformat.info/info/charset/UTF-8/list.htm>
 //!   - Implements `Display` and has the function `.repeat(n)` where `n` is `usize`
+//!   - Exposes each character's `char`, codepoint, Unicode name and block, with lookup by character or name
+//!   - Falls back to ASCII substitutes (`|`, `+`, `` ` ``, `-`, ...) with `set_charset(Charset::Ascii)` or `Charset::detect()`
+//! - **Box-Drawing Junctions**: `Junction` describes a cell by the weight of its four edges and maps it to and from the matching box-drawing glyph, so overlapping lines merge into the right character
+//! - **Tables**: `Table` renders headers and rows of styled cells with per-column alignment, width limits, wrapping or truncation, and slim, bold, double, rounded, ASCII or Markdown borders
+//! - **Panels**: `Panel` frames multi-line styled content with a border, padding, margin, a title and subtitle, at a fixed or fit-to-content width
+//! - **Progress Bars**: `ProgressBar` draws templated progress (`{bar} {percent} {eta} {msg}`) with eighth-block precision, styled segments, throughput and ETA, redrawn in place on terminals and as plain lines elsewhere
+//! - **Live Regions**: `LiveRegion` keeps a set of lines (e.g. several progress bars) at the bottom of the terminal, updated from any thread and redrawn in place while other output prints above it
+//! - **Spinners**: `Spinner` animates one of the built-in `Frames` (dots, line, arc, braille, bouncing bar, arrowheads, ...) or custom frames, ticked manually or from a background thread, and finishes with a success or failure glyph
+//! - Cursor movement, erasing, scroll regions, alternate screen and bracketed paste sequences with `Control`, and `ControlGuard` to undo them on drop
+//! - Clickable OSC 8 hyperlinks with `Hyperlink`, falling back to `text (url)` where unsupported
+//! - Window title, clipboard (OSC 52), desktop notification and terminal color sequences with `Osc`
+//! - `StyledWriter` writes only the escape codes that change between styled writes, without allocating, and disables colors on non-terminal streams
+//! - Allocation-free `Painted` wrappers from `paint()`, `painter()` and `paint!()` that keep width and precision flags
+//! - Compile-time styled `&'static str`s with `style_const!()`, and `const fn` `Paint` styles for `static`s
+//! - `#[derive(Styled)]` for status enums and structs with `#[dekor(...)]` styles, behind the `derive` feature
+//! - `sprintln!`, `seprintln!` and `swrite!` with inline styled placeholders like `{:[Bold, FGRed]}`, behind the `macros` feature
+//! - Themeable, width-aware `Debug` pretty-printing with `PrettyDebug`
+//! - Dependency-free JSON pretty-printing with `PrettyJson`: themed keys and values, indentation, compact mode, tree guides, and parse errors with a caret under the offending column
+//! - Compiler-style diagnostics with `Report`: source snippets with a line-number gutter, primary and secondary labels, multi-line spans, notes and help, and an ASCII fallback
+//! - Myers line and word diffs with `Diff`, rendered unified or side by side with intraline highlights on changed words
+//! - Style-preserving `text::wrap` and `text::fill` with prefixes, hanging indents, and greedy or minimum-raggedness line breaking
+//! - Display-width aware `text::pad_left`, `pad_right`, `center` and `truncate_with_ellipsis` for styled strings and `Painted` values
 //! ## Getting Started
 //! To start using Dekor, add the following to your `Cargo.toml`:
 //! ```toml
 //! [dependencies]
 //! dekor = "0.2.1"
 //! ```
-//! - Minimum supported Rust version: `1.56.1`
+//! - Minimum supported Rust version: `1.70.0`
 //! ## Usage
 //! ### Basic Text Styling
 //! ```rust
@@ -69,6 +91,7 @@
 //! ![OutputExample](OutputExample.png)
 //! - Characters: `Utf8::VPipeSlim`, `Utf8::JointPipeSlim`, `Utf8::NodePipeCurved`, `Utf8::HPipeSlim`, and `Utf8::ModLetterDownArrowhead`
 //! - Styles: `FGBlue`, `Bold`
+//!
 //! ![OutputExample2](OutputExample2.png)
 //! ```rust
 //! use dekor::*;
@@ -83,6 +106,21 @@
 //! ```
 pub mod style;
 pub mod characters;
+pub mod junction;
+pub mod text;
+pub mod border;
+pub mod table;
+pub mod panel;
+pub mod progress;
+pub mod live;
+pub mod spinner;
+pub mod control;
+pub mod osc;
+pub mod writer;
+pub mod pretty;
+pub mod json;
+pub mod report;
+pub mod diff;
 
 pub use style::HexError;
 pub use style::as_rgb;
@@ -90,7 +128,62 @@ pub use style::to_rgb;
 pub use style::style;
 pub use style::styler;
 pub use style::Style;
+pub use style::Color;
+pub use style::Paint;
+pub use style::Painted;
+pub use style::paint;
+pub use style::painter;
+pub use style::Styled;
 pub use characters::Utf8;
+pub use characters::UnicodeBlock;
+pub use characters::Charset;
+pub use characters::Glyph;
+pub use characters::charset;
+pub use characters::set_charset;
+pub use junction::Junction;
+pub use junction::Weight;
+pub use text::Align;
+pub use text::WrapAlgorithm;
+pub use text::WrapOptions;
+pub use border::Border;
+pub use table::Cell;
+pub use table::Column;
+pub use table::Overflow;
+pub use table::Table;
+pub use panel::Panel;
+pub use panel::Spacing;
+pub use progress::ProgressBar;
+pub use live::LiveLine;
+pub use live::LiveRegion;
+pub use spinner::Frames;
+pub use spinner::Spinner;
+pub use control::Control;
+pub use control::ControlGuard;
+pub use osc::Hyperlink;
+pub use osc::Osc;
+pub use osc::hyperlinks;
+pub use osc::hyperlinks_supported;
+pub use osc::set_hyperlinks;
+pub use writer::StyledWriter;
+pub use writer::color_supported;
+pub use writer::stdout_color;
+pub use writer::stderr_color;
+pub use pretty::PrettyDebug;
+pub use pretty::Theme;
+pub use json::JsonError;
+pub use json::PrettyJson;
+pub use report::Level;
+pub use report::Report;
+pub use diff::Diff;
+pub use diff::Edit;
+pub use diff::Layout;
+pub use diff::diff;
+pub use diff::diff_lines;
+pub use diff::diff_words;
+#[cfg(feature = "derive")]
+pub use dekor_derive::Styled;
+#[cfg(feature = "macros")]
+pub use dekor_derive::{sprint, sprintln, seprint, seprintln, swrite, swriteln};
 
 
 #[doc = include_str!("../README.md")]
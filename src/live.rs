@@ -0,0 +1,285 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::characters::Utf8;
+use crate::control::Control;
+use crate::text;
+
+/// A fixed number of lines kept at the bottom of the terminal and redrawn in place, e.g. one line
+/// per progress bar of parallel downloads.
+///
+/// The region is cheap to clone and every clone, as well as the `LiveLine` handles it hands out,
+/// can be sent to another thread. All output goes through a single lock, so lines printed above
+/// the region with `println` never tear the region itself.
+///
+/// On a terminal each update moves the cursor back up over the region (`ESC [ n A`), erases and
+/// rewrites its lines and leaves the cursor below it. Elsewhere updates are only recorded, and
+/// the final state of the region is written once when the last handle is dropped.
+///
+/// Every line must take up a single row for the region to be redrawn in place, so newlines in
+/// the content are replaced with spaces and lines wider than the terminal are truncated.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let region = LiveRegion::new(2);
+/// let handles: Vec<_> = (0..2).map(|i| {
+///     let line = region.line(i);
+///     std::thread::spawn(move || {
+///         for percent in (0..=100).step_by(25) {
+///             line.set(format!("download {}: {:>3}%", i, percent)).unwrap();
+///         }
+///     })
+/// }).collect();
+///
+/// region.println("starting downloads").unwrap();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// ```
+///
+pub struct LiveRegion<W: Write + Send = io::Stderr> {
+    inner: Arc<Mutex<Inner<W>>>,
+}
+
+/// A handle to a single line of a `LiveRegion`.
+pub struct LiveLine<W: Write + Send = io::Stderr> {
+    region: LiveRegion<W>,
+    index: usize,
+}
+
+struct Inner<W: Write> {
+    writer: W,
+    tty: bool,
+    lines: Vec<String>,
+    /// Number of lines currently drawn above the cursor
+    drawn: usize,
+    width: usize,
+    clear_on_drop: bool,
+}
+
+impl LiveRegion<io::Stderr> {
+    /// Creates a region of `lines` empty lines drawn to standard error.
+    pub fn new(lines: usize) -> Self {
+        let tty = io::stderr().is_terminal();
+        Self::with_writer(io::stderr(), lines).tty(tty)
+    }
+}
+
+impl<W: Write + Send> LiveRegion<W> {
+    /// Creates a region of `lines` empty lines drawn to `writer`.
+    ///
+    /// The writer is treated as a non-terminal unless `tty(true)` is set.
+    pub fn with_writer(writer: W, lines: usize) -> Self {
+        let width = std::env::var("COLUMNS").ok().and_then(|c| c.parse().ok()).unwrap_or(80);
+        let inner = Inner { writer, tty: false, lines: vec![String::new(); lines], drawn: 0, width, clear_on_drop: false };
+        LiveRegion { inner: Arc::new(Mutex::new(inner)) }
+    }
+
+    /// Sets whether the writer is a terminal, which enables redrawing in place.
+    pub fn tty(self, tty: bool) -> Self {
+        self.lock().tty = tty;
+        self
+    }
+
+    /// Sets the width of the terminal in columns, beyond which lines are truncated. Defaults to the
+    /// `COLUMNS` environment variable, or 80.
+    pub fn width(self, width: usize) -> Self {
+        self.lock().width = width;
+        self
+    }
+
+    /// Erases the region when it is dropped instead of leaving its final lines on screen.
+    pub fn clear_on_drop(self, clear: bool) -> Self {
+        self.lock().clear_on_drop = clear;
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner<W>> {
+        // A panic while drawing leaves nothing inconsistent worth propagating
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the number of lines in the region.
+    pub fn len(&self) -> usize {
+        self.lock().lines.len()
+    }
+
+    /// Returns `true` if the region has no lines.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a handle to the line at `index`, which can be moved to another thread.
+    pub fn line(&self, index: usize) -> LiveLine<W> {
+        LiveLine { region: self.clone(), index }
+    }
+
+    /// Replaces the line at `index` and redraws the region. Indices past the end of the region
+    /// are ignored, and newlines in `content` are replaced with spaces.
+    pub fn set_line<D: std::fmt::Display>(&self, index: usize, content: D) -> io::Result<()> {
+        let mut inner = self.lock();
+        match inner.lines.get_mut(index) {
+            Some(line) => *line = content.to_string().replace("\r\n", " ").replace(['\n', '\r'], " "),
+            None => return Ok(()),
+        }
+        inner.redraw(String::new())
+    }
+
+    /// Prints `content` above the region, which is redrawn below it.
+    pub fn println<D: std::fmt::Display>(&self, content: D) -> io::Result<()> {
+        let mut inner = self.lock();
+        if !inner.tty {
+            writeln!(inner.writer, "{}", content)?;
+            return inner.writer.flush();
+        }
+        inner.redraw(format!("{}\n", content))
+    }
+
+    /// Erases the region from the screen; it is drawn again on the next update.
+    pub fn clear(&self) -> io::Result<()> {
+        self.lock().clear()
+    }
+}
+
+impl<W: Write> Inner<W> {
+    /// Moves back over the drawn lines and rewrites them, with `above` printed first.
+    fn redraw(&mut self, above: String) -> io::Result<()> {
+        if !self.tty {
+            return Ok(());
+        }
+        let mut out = self.rewind();
+        if !above.is_empty() {
+            out.push_str(&Control::EraseBelow.to_string());
+            out.push_str(&above);
+        }
+        // A line wrapping onto a second row would leave `drawn` short of the rows to move back over
+        let ellipsis = Utf8::HorizontalEllipsis.to_string();
+        for line in &self.lines {
+            out.push_str(&Control::EraseLine.to_string());
+            out.push_str(&text::truncate(line, self.width, &ellipsis));
+            out.push('\n');
+        }
+        self.drawn = self.lines.len();
+        self.writer.write_all(out.as_bytes())?;
+        self.writer.flush()
+    }
+
+    fn rewind(&self) -> String {
+        if self.drawn > 0 { format!("\r{}", Control::Up(self.drawn as u16)) } else { String::new() }
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        if !self.tty || self.drawn == 0 {
+            return Ok(());
+        }
+        let out = format!("{}{}", self.rewind(), Control::EraseBelow);
+        self.drawn = 0;
+        self.writer.write_all(out.as_bytes())?;
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Drop for Inner<W> {
+    fn drop(&mut self) {
+        // Errors cannot be reported from `drop`
+        let _ = if !self.tty {
+            let lines: String = self.lines.iter().map(|l| format!("{}\n", l)).collect();
+            self.writer.write_all(lines.as_bytes()).and_then(|_| self.writer.flush())
+        } else if self.clear_on_drop {
+            self.clear()
+        } else {
+            self.writer.flush()
+        };
+    }
+}
+
+impl<W: Write + Send> Clone for LiveRegion<W> {
+    fn clone(&self) -> Self {
+        LiveRegion { inner: Arc::clone(&self.inner) }
+    }
+}
+
+impl<W: Write + Send> LiveLine<W> {
+    /// Replaces the content of the line and redraws the region.
+    pub fn set<D: std::fmt::Display>(&self, content: D) -> io::Result<()> {
+        self.region.set_line(self.index, content)
+    }
+
+    /// Returns the region this line belongs to.
+    pub fn region(&self) -> &LiveRegion<W> {
+        &self.region
+    }
+}
+
+impl<W: Write + Send> Clone for LiveLine<W> {
+    fn clone(&self) -> Self {
+        LiveLine { region: self.region.clone(), index: self.index }
+    }
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An in-memory writer whose contents stay readable after the region is dropped.
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Buffer {
+        fn take(&self) -> String {
+            String::from_utf8(std::mem::take(&mut *self.0.lock().unwrap())).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_redraw_in_place() {
+        let buffer = Buffer::default();
+        let region = LiveRegion::with_writer(buffer.clone(), 2).tty(true).clear_on_drop(true);
+
+        region.set_line(0, "a").unwrap();
+        assert_eq!(buffer.take(), "\x1b[2Ka\n\x1b[2K\n");
+        region.line(1).set("b").unwrap();
+        assert_eq!(buffer.take(), "\r\x1b[2A\x1b[2Ka\n\x1b[2Kb\n");
+        region.println("log").unwrap();
+        assert_eq!(buffer.take(), "\r\x1b[2A\x1b[Jlog\n\x1b[2Ka\n\x1b[2Kb\n");
+        drop(region);
+        assert_eq!(buffer.take(), "\r\x1b[2A\x1b[J");
+    }
+
+    #[test]
+    fn test_lines_take_one_row() {
+        let buffer = Buffer::default();
+        let region = LiveRegion::with_writer(buffer.clone(), 1).tty(true).width(5);
+        region.set_line(0, "a\nb\r\nc").unwrap();
+        assert_eq!(buffer.take(), "\x1b[2Ka b c\n");
+        region.set_line(0, "a long line").unwrap();
+        assert_eq!(buffer.take(), "\r\x1b[1A\x1b[2Ka lo…\n");
+    }
+
+    #[test]
+    fn test_non_tty_writes_final_state() {
+        let buffer = Buffer::default();
+        let region = LiveRegion::with_writer(buffer.clone(), 2);
+        let line = region.line(1);
+        std::thread::spawn(move || line.set("done").unwrap()).join().unwrap();
+        region.println("log").unwrap();
+        region.set_line(5, "ignored").unwrap();
+        drop(region);
+        assert_eq!(buffer.take(), "log\n\ndone\n");
+    }
+}
@@ -0,0 +1,316 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::style::{style, Style};
+
+/// String terminator (`ESC \`) ending every OSC sequence written by this module.
+const ST: &str = "\x1b\\";
+
+const HYPERLINKS_DETECT: u8 = 0;
+const HYPERLINKS_OFF: u8 = 1;
+const HYPERLINKS_ON: u8 = 2;
+
+static HYPERLINKS: AtomicU8 = AtomicU8::new(HYPERLINKS_DETECT);
+
+/// Sets whether `Hyperlink`s are written as OSC 8 sequences or as `text (url)`.
+///
+/// Until this is called, the first use detects support with `hyperlinks_supported()`, so that
+/// the URL stays visible on terminals without OSC 8 support and in redirected output.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let link = Hyperlink::new("https://example.com", "example");
+/// set_hyperlinks(false);
+/// assert_eq!(link.to_string(), "example (https://example.com)");
+/// set_hyperlinks(true);
+/// assert_eq!(link.to_string(), "\x1b]8;;https://example.com\x1b\\example\x1b]8;;\x1b\\");
+/// ```
+///
+pub fn set_hyperlinks(enabled: bool) {
+    HYPERLINKS.store(if enabled { HYPERLINKS_ON } else { HYPERLINKS_OFF }, Ordering::Relaxed);
+}
+
+/// Returns whether `Hyperlink`s are written as OSC 8 sequences, see `set_hyperlinks`.
+pub fn hyperlinks() -> bool {
+    match HYPERLINKS.load(Ordering::Relaxed) {
+        HYPERLINKS_DETECT => {
+            let detected = if hyperlinks_supported() { HYPERLINKS_ON } else { HYPERLINKS_OFF };
+            // A concurrent `set_hyperlinks` wins over the detection
+            match HYPERLINKS.compare_exchange(HYPERLINKS_DETECT, detected, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => detected == HYPERLINKS_ON,
+                Err(current) => current == HYPERLINKS_ON,
+            }
+        },
+        current => current == HYPERLINKS_ON,
+    }
+}
+
+/// Detects whether standard output is a terminal known to support OSC 8 hyperlinks.
+///
+/// The check relies on the variables set by the terminal emulators themselves (`TERM_PROGRAM`,
+/// `VTE_VERSION`, `WT_SESSION`, `KONSOLE_VERSION`, `TERM`...) and is disabled inside `screen`,
+/// which does not pass the sequences through.
+pub fn hyperlinks_supported() -> bool {
+    use std::io::IsTerminal;
+    if !std::io::stdout().is_terminal() {
+        return false;
+    }
+    let var = |name: &str| std::env::var(name).unwrap_or_default();
+    let term = var("TERM");
+    if term.starts_with("screen") {
+        return false;
+    }
+    let program = var("TERM_PROGRAM");
+    ["iTerm.app", "WezTerm", "vscode", "ghostty", "Hyper", "Tabby"].contains(&program.as_str())
+        || var("VTE_VERSION").parse::<u32>().is_ok_and(|v| v >= 5000)
+        || !var("WT_SESSION").is_empty()
+        || !var("KONSOLE_VERSION").is_empty()
+        || ["kitty", "alacritty", "foot", "ghostty", "wezterm", "contour"].iter().any(|t| term.contains(t))
+}
+
+/// A clickable link written with the OSC 8 escape sequence.
+///
+/// The text may be any `Display` value, including the output of `style()` and `styler()`, and
+/// can be styled by the link itself with `style`. Links sharing an `id` are highlighted together
+/// by the terminal, which is useful when one link is split across several lines or table cells.
+///
+/// While hyperlinks are disabled (see `set_hyperlinks`), or for a link forced off with
+/// `enabled(false)`, the link is written as `text (url)`, or just the URL when the text is the
+/// URL itself.
+///
+/// Since `Hyperlink` implements `Display`, it can in turn be passed to `style()` or used as a
+/// `Table` cell; OSC sequences have no display width.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let link = Hyperlink::new("https://example.com/issues/42", "#42")
+///     .id("issue-42")
+///     .style([Style::Underline])
+///     .enabled(true);
+/// assert_eq!(link.to_string(), concat!(
+///     "\x1b]8;id=issue-42;https://example.com/issues/42\x1b\\",
+///     "\x1b[4m#42\x1b[0m",
+///     "\x1b]8;;\x1b\\",
+/// ));
+///
+/// let path = Hyperlink::new("file:///tmp/report.txt", style([Style::Bold], "report.txt")).enabled(false);
+/// assert_eq!(path.to_string(), "\x1b[1mreport.txt\x1b[0m (file:///tmp/report.txt)");
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hyperlink {
+    url: String,
+    text: String,
+    id: Option<String>,
+    styles: Vec<Style>,
+    enabled: Option<bool>,
+}
+
+impl Hyperlink {
+    /// Creates a link to `url` shown as `text`.
+    pub fn new<U: std::fmt::Display, D: std::fmt::Display>(url: U, text: D) -> Self {
+        // Control characters would end the sequence early
+        let url = url.to_string().chars().filter(|c| !c.is_control()).collect();
+        Hyperlink { url, text: text.to_string(), id: None, styles: Vec::new(), enabled: None }
+    }
+
+    /// Sets the `id` parameter, which groups links to the same target.
+    pub fn id<D: std::fmt::Display>(mut self, id: D) -> Self {
+        // `:` and `;` separate the parameters and the URL
+        let id = id.to_string().chars().filter(|c| !c.is_control() && *c != ':' && *c != ';').collect();
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the styles applied to the text.
+    pub fn style<I: IntoIterator<Item = Style>>(mut self, styles: I) -> Self {
+        self.styles = styles.into_iter().collect();
+        self
+    }
+
+    /// Forces OSC 8 output on or off for this link, regardless of the global setting.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Returns the target of the link.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl std::fmt::Display for Hyperlink {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let text = if self.styles.is_empty() { self.text.clone() } else { style(self.styles.iter().copied(), &self.text) };
+        if !self.enabled.unwrap_or_else(hyperlinks) {
+            return if self.text == self.url { write!(f, "{}", text) } else { write!(f, "{} ({})", text, self.url) };
+        }
+        match &self.id {
+            Some(id) => write!(f, "\x1b]8;id={};{}{}", id, self.url, ST)?,
+            None => write!(f, "\x1b]8;;{}{}", self.url, ST)?,
+        }
+        write!(f, "{}\x1b]8;;{}", text, ST)
+    }
+}
+
+/// Operating System Command sequences for the terminal window, clipboard, notifications and colors.
+///
+/// Like `Control`, each variant implements `Display` and can be written to any `io::Write` with
+/// `write_to`. Control characters are removed from the text of titles and notifications, which
+/// would otherwise end the sequence early. Colors are `(r, g, b)` tuples, as returned by `as_rgb`
+/// and `to_rgb`.
+///
+/// Terminals decide for themselves which commands to honor; reading the clipboard and changing
+/// colors in particular are often disabled, in which case the sequences are silently ignored.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// assert_eq!(Osc::Title("build: ok".into()).to_string(), "\x1b]2;build: ok\x1b\\");
+/// assert_eq!(Osc::Clipboard("hi".into()).to_string(), "\x1b]52;c;aGk=\x1b\\");
+///
+/// let cursor = Osc::CursorColor(to_rgb("#ff8800").unwrap());
+/// assert_eq!(cursor.to_string(), "\x1b]12;rgb:ff/88/00\x1b\\");
+///
+/// let mut out = Vec::new();
+/// Osc::Notify { title: "cargo".into(), body: "tests passed".into() }.write_to(&mut out).unwrap();
+/// assert_eq!(out, b"\x1b]777;notify;cargo;tests passed\x1b\\");
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Osc {
+    /// Set the window title and icon name (OSC 0)
+    TitleAndIconName(String),
+    /// Set the icon name, or tab title (OSC 1)
+    IconName(String),
+    /// Set the window title (OSC 2)
+    Title(String),
+    /// Copy text to the system clipboard, base64 encoded (OSC 52)
+    Clipboard(String),
+    /// Show a desktop notification with a message (OSC 9, iTerm2, Windows Terminal, kitty...)
+    Message(String),
+    /// Show a desktop notification with a title and body (OSC 777, VTE based terminals, foot...)
+    Notify { title: String, body: String },
+    /// Change the color of an entry of the 256 color palette (OSC 4)
+    PaletteColor { index: u8, rgb: (u8, u8, u8) },
+    /// Restore an entry of the palette to its default (OSC 104)
+    ResetPaletteColor(u8),
+    /// Change the default foreground color (OSC 10)
+    ForegroundColor((u8, u8, u8)),
+    /// Restore the default foreground color (OSC 110)
+    ResetForegroundColor,
+    /// Change the default background color (OSC 11)
+    BackgroundColor((u8, u8, u8)),
+    /// Restore the default background color (OSC 111)
+    ResetBackgroundColor,
+    /// Change the cursor color (OSC 12)
+    CursorColor((u8, u8, u8)),
+    /// Restore the default cursor color (OSC 112)
+    ResetCursorColor,
+}
+
+impl Osc {
+    /// Writes the sequence to `writer`.
+    pub fn write_to<W: std::io::Write + ?Sized>(&self, writer: &mut W) -> std::io::Result<()> {
+        write!(writer, "{}", self)
+    }
+}
+
+impl std::fmt::Display for Osc {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let clean = |s: &str| -> String { s.chars().filter(|c| !c.is_control()).collect() };
+        let color = |(r, g, b): (u8, u8, u8)| format!("rgb:{:02x}/{:02x}/{:02x}", r, g, b);
+        match self {
+            Osc::TitleAndIconName(title) => write!(f, "\x1b]0;{}{}", clean(title), ST),
+            Osc::IconName(name) => write!(f, "\x1b]1;{}{}", clean(name), ST),
+            Osc::Title(title) => write!(f, "\x1b]2;{}{}", clean(title), ST),
+            Osc::Clipboard(content) => write!(f, "\x1b]52;c;{}{}", base64(content.as_bytes()), ST),
+            Osc::Message(message) => write!(f, "\x1b]9;{}{}", clean(message), ST),
+            // `;` separates the title from the body
+            Osc::Notify { title, body } => write!(f, "\x1b]777;notify;{};{}{}", clean(title).replace(';', ","), clean(body), ST),
+            Osc::PaletteColor { index, rgb } => write!(f, "\x1b]4;{};{}{}", index, color(*rgb), ST),
+            Osc::ResetPaletteColor(index) => write!(f, "\x1b]104;{}{}", index, ST),
+            Osc::ForegroundColor(rgb) => write!(f, "\x1b]10;{}{}", color(*rgb), ST),
+            Osc::ResetForegroundColor => write!(f, "\x1b]110{}", ST),
+            Osc::BackgroundColor(rgb) => write!(f, "\x1b]11;{}{}", color(*rgb), ST),
+            Osc::ResetBackgroundColor => write!(f, "\x1b]111{}", ST),
+            Osc::CursorColor(rgb) => write!(f, "\x1b]12;{}{}", color(*rgb), ST),
+            Osc::ResetCursorColor => write!(f, "\x1b]112{}", ST),
+        }
+    }
+}
+
+/// Encodes `bytes` as standard, padded base64.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text;
+
+    #[test]
+    fn test_fallback_and_sanitizing() {
+        let link = Hyperlink::new("https://a.io/\x07x", "https://a.io/x").id("a;b:c").enabled(false);
+        assert_eq!(link.to_string(), "https://a.io/x");
+        assert_eq!(link.clone().enabled(true).to_string(), "\x1b]8;id=abc;https://a.io/x\x1b\\https://a.io/x\x1b]8;;\x1b\\");
+    }
+
+    #[test]
+    fn test_composes_with_style() {
+        let link = style([Style::Bold], Hyperlink::new("https://a.io", "docs").enabled(true));
+        assert_eq!(link, "\x1b[1m\x1b]8;;https://a.io\x1b\\docs\x1b]8;;\x1b\\\x1b[0m");
+        assert_eq!(text::display_width(&link), 4);
+        assert_eq!(text::strip_ansi(&link), "docs");
+    }
+
+    #[test]
+    fn test_set_hyperlinks_overrides_detection() {
+        set_hyperlinks(false);
+        assert!(!hyperlinks());
+        assert_eq!(Hyperlink::new("https://a.io", "a").to_string(), "a (https://a.io)");
+        set_hyperlinks(true);
+        assert!(hyperlinks());
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64("héllo wörld".as_bytes()), "aMOpbGxvIHfDtnJsZA==");
+    }
+
+    #[test]
+    fn test_osc_sequences() {
+        assert_eq!(Osc::Title("a\x1b]b\x07".into()).to_string(), "\x1b]2;a]b\x1b\\");
+        assert_eq!(Osc::Notify { title: "a;b".into(), body: "c;d".into() }.to_string(), "\x1b]777;notify;a,b;c;d\x1b\\");
+        assert_eq!(Osc::PaletteColor { index: 1, rgb: (255, 0, 10) }.to_string(), "\x1b]4;1;rgb:ff/00/0a\x1b\\");
+        assert_eq!(Osc::ResetPaletteColor(1).to_string(), "\x1b]104;1\x1b\\");
+        assert_eq!(Osc::ResetBackgroundColor.to_string(), "\x1b]111\x1b\\");
+    }
+}
@@ -0,0 +1,312 @@
+use crate::border::Border;
+use crate::characters::Utf8;
+use crate::style::{style, Style};
+use crate::text::{self, Align};
+
+/// Space around the four sides of a block, in lines (top and bottom) and columns (left and right).
+///
+/// Converts from a single `usize` for the same spacing on every side, and from a
+/// `(vertical, horizontal)` tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Spacing {
+    pub top: usize,
+    pub right: usize,
+    pub bottom: usize,
+    pub left: usize,
+}
+
+impl Spacing {
+    /// Creates spacing from its `top`, `right`, `bottom` and `left` sides.
+    pub const fn new(top: usize, right: usize, bottom: usize, left: usize) -> Self {
+        Spacing { top, right, bottom, left }
+    }
+}
+
+impl From<usize> for Spacing {
+    fn from(n: usize) -> Self {
+        Spacing::new(n, n, n, n)
+    }
+}
+
+impl From<(usize, usize)> for Spacing {
+    fn from((vertical, horizontal): (usize, usize)) -> Self {
+        Spacing::new(vertical, horizontal, vertical, horizontal)
+    }
+}
+
+/// Draws a border around a block of text, with an optional title in the top border and subtitle
+/// in the bottom border.
+///
+/// The content may contain escape codes and several lines; it is measured by display width and,
+/// when the panel has a fixed width, wrapped to fit while keeping its styles. Without a fixed
+/// width the panel fits its content and titles.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let panel = Panel::new("Build finished\nin 2.4s")
+///     .title("cargo")
+///     .border(Border::Rounded)
+///     .padding((0, 1));
+///
+/// assert_eq!(panel.to_string(), "\
+/// ╭─ cargo ────────╮
+/// │ Build finished │
+/// │ in 2.4s        │
+/// ╰────────────────╯
+/// ");
+///
+/// let fixed = Panel::new("A long message that has to wrap")
+///     .subtitle("1/3")
+///     .subtitle_align(Align::Right)
+///     .border(Border::Ascii)
+///     .width(16);
+///
+/// assert_eq!(fixed.to_string(), "\
+/// +--------------+
+/// |A long message|
+/// |that has to   |
+/// |wrap          |
+/// +-------- 1/3 -+
+/// ");
+/// ```
+///
+#[derive(Debug, Clone, Default)]
+pub struct Panel {
+    content: String,
+    border: Border,
+    border_style: Vec<Style>,
+    padding: Spacing,
+    margin: Spacing,
+    align: Align,
+    title: Option<String>,
+    title_align: Align,
+    subtitle: Option<String>,
+    subtitle_align: Align,
+    width: Option<usize>,
+}
+
+impl Panel {
+    /// Creates a panel around `content` with `Border::Slim` borders and no padding.
+    pub fn new<D: std::fmt::Display>(content: D) -> Self {
+        Panel { content: content.to_string(), ..Self::default() }
+    }
+
+    /// Sets the border preset. `Border::Markdown` is drawn as `Border::Ascii`.
+    pub fn border(mut self, border: Border) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the styles applied to the border lines.
+    pub fn border_style<I: IntoIterator<Item = Style>>(mut self, styles: I) -> Self {
+        self.border_style = styles.into_iter().collect();
+        self
+    }
+
+    /// Sets the space between the border and the content.
+    pub fn padding<S: Into<Spacing>>(mut self, padding: S) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the space around the outside of the border.
+    pub fn margin<S: Into<Spacing>>(mut self, margin: S) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    /// Sets the alignment of the content lines.
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets the title shown in the top border.
+    pub fn title<D: std::fmt::Display>(mut self, title: D) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Sets the position of the title within the top border.
+    pub fn title_align(mut self, align: Align) -> Self {
+        self.title_align = align;
+        self
+    }
+
+    /// Sets the subtitle shown in the bottom border.
+    pub fn subtitle<D: std::fmt::Display>(mut self, subtitle: D) -> Self {
+        self.subtitle = Some(subtitle.to_string());
+        self
+    }
+
+    /// Sets the position of the subtitle within the bottom border.
+    pub fn subtitle_align(mut self, align: Align) -> Self {
+        self.subtitle_align = align;
+        self
+    }
+
+    /// Fixes the total width of the panel, borders and padding included but margins excluded.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Styles a run of border characters.
+    fn paint(&self, border: String) -> String {
+        if self.border_style.is_empty() || border.is_empty() {
+            border
+        } else {
+            style(self.border_style.iter().copied(), border)
+        }
+    }
+
+    /// Draws a horizontal border of `width` columns between two corners with an optional label.
+    fn rule(&self, border: Border, width: usize, corners: (char, char), label: Option<&str>, align: Align) -> String {
+        let horizontal = border.glyph(false, true, false, true).to_string();
+        let label = match label {
+            // Keep at least one horizontal on each side of the label, and one column for its text
+            Some(label) if width >= 5 => {
+                let ellipsis = Utf8::HorizontalEllipsis.to_string();
+                format!(" {} ", text::truncate(label, width - 4, &ellipsis))
+            },
+            _ => String::new(),
+        };
+
+        if label.is_empty() {
+            return self.paint(format!("{}{}{}", corners.0, horizontal.repeat(width), corners.1));
+        }
+
+        let free = width.saturating_sub(text::display_width(&label));
+        let left = match align {
+            Align::Left => 1,
+            Align::Center => free / 2,
+            Align::Right => free.saturating_sub(1),
+        }
+        .min(free);
+        format!("{}{}{}",
+            self.paint(format!("{}{}", corners.0, horizontal.repeat(left))),
+            label,
+            self.paint(format!("{}{}", horizontal.repeat(free - left), corners.1)),
+        )
+    }
+}
+
+impl std::fmt::Display for Panel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let border = match self.border.resolve() {
+            Border::Markdown => Border::Ascii,
+            border => border,
+        };
+        let framed = border != Border::None;
+        let frame = if framed { 2 } else { 0 };
+        let padding = self.padding.left + self.padding.right;
+        let label_width = |label: &Option<String>| label.as_deref().map_or(0, |l| text::display_width(l) + 4);
+
+        let content_width = match self.width {
+            Some(width) => width.saturating_sub(frame + padding).max(1),
+            None => {
+                let widest = self.content.lines().map(text::display_width).max().unwrap_or(0);
+                let labels = label_width(&self.title).max(label_width(&self.subtitle));
+                widest.max(labels.saturating_sub(padding))
+            },
+        };
+        let inner = content_width + padding;
+
+        let mut lines: Vec<String> = Vec::new();
+        lines.extend((0..self.padding.top).map(|_| String::new()));
+        // An empty panel still has one empty content line
+        let content = if self.content.is_empty() { vec![""] } else { self.content.lines().collect() };
+        for line in content {
+            if text::display_width(line) <= content_width {
+                lines.push(line.to_string());
+            } else {
+                lines.extend(text::wrap(line, content_width));
+            }
+        }
+        lines.extend((0..self.padding.bottom).map(|_| String::new()));
+
+        let margin_left = " ".repeat(self.margin.left);
+        let margin_right = " ".repeat(self.margin.right);
+        for _ in 0..self.margin.top {
+            writeln!(f)?;
+        }
+        if framed {
+            let corners = (border.glyph(false, true, true, false), border.glyph(false, false, true, true));
+            let top = self.rule(border, inner, corners, self.title.as_deref(), self.title_align);
+            writeln!(f, "{}{}{}", margin_left, top, margin_right)?;
+        }
+        let vertical = if framed { self.paint(border.glyph(true, false, true, false).to_string()) } else { String::new() };
+        for line in lines {
+            writeln!(f, "{}{}{}{}{}{}{}",
+                margin_left,
+                vertical,
+                " ".repeat(self.padding.left),
+                text::pad(&line, content_width, self.align),
+                " ".repeat(self.padding.right),
+                vertical,
+                margin_right,
+            )?;
+        }
+        if framed {
+            let corners = (border.glyph(true, true, false, false), border.glyph(true, false, false, true));
+            let bottom = self.rule(border, inner, corners, self.subtitle.as_deref(), self.subtitle_align);
+            writeln!(f, "{}{}{}", margin_left, bottom, margin_right)?;
+        }
+        for _ in 0..self.margin.bottom {
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_styled_border_and_margin() {
+        let panel = Panel::new("hi")
+            .border(Border::Double)
+            .border_style([Style::FGBlue])
+            .margin((1, 2));
+        let blue = |s: &str| format!("\x1b[34m{}\x1b[0m", s);
+        assert_eq!(panel.to_string(), format!(
+            "\n  {}  \n  {}hi{}  \n  {}  \n\n",
+            blue("╔══╗"), blue("║"), blue("║"), blue("╚══╝"),
+        ));
+    }
+
+    #[test]
+    fn test_title_alignment_and_fit() {
+        let panel = Panel::new("x")
+            .title("Title")
+            .title_align(Align::Center)
+            .border(Border::Bold);
+        assert_eq!(panel.to_string(), "┏━ Title ━┓\n┃x        ┃\n┗━━━━━━━━━┛\n");
+    }
+
+    #[test]
+    fn test_title_in_small_fixed_widths() {
+        for align in [Align::Left, Align::Center, Align::Right] {
+            for width in 1..=8 {
+                let panel = Panel::new("x").title("abc").title_align(align).width(width).to_string();
+                let expected = width.max(3);
+                assert!(panel.lines().all(|line| text::display_width(line) == expected), "{:?} at {}: {:?}", align, width, panel);
+            }
+        }
+        assert_eq!(Panel::new("x").title("abc").width(5).to_string(), "┌───┐\n│x  │\n└───┘\n");
+        assert_eq!(Panel::new("hi").title("Title").width(6).to_string(), "┌────┐\n│hi  │\n└────┘\n");
+        assert_eq!(Panel::new("hi").title("Title").width(7).to_string(), "┌─ … ─┐\n│hi   │\n└─────┘\n");
+    }
+
+    #[test]
+    fn test_empty_content() {
+        assert_eq!(Panel::new("").to_string(), "┌┐\n││\n└┘\n");
+        assert_eq!(Panel::new("").title("t").to_string(), "┌─ t ─┐\n│     │\n└─────┘\n");
+    }
+}
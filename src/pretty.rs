@@ -0,0 +1,458 @@
+use crate::characters::Utf8;
+use crate::style::{Color, Paint};
+use crate::text;
+
+/// The styles used to highlight structured data, such as `Debug` and JSON output.
+///
+/// The default theme colors each kind of value differently, `Theme::plain()` disables every
+/// style, and individual fields can be changed with struct update syntax.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let theme = Theme { key: Paint::new().bold(), ..Theme::plain() };
+/// assert!(theme.string.is_plain());
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// Field names and object keys
+    pub key: Paint,
+    /// Type and enum variant names
+    pub type_name: Paint,
+    /// Strings and characters
+    pub string: Paint,
+    /// Numbers
+    pub number: Paint,
+    /// `true` and `false`
+    pub boolean: Paint,
+    /// `None` and `null`
+    pub null: Paint,
+    /// Brackets, commas, colons and other punctuation
+    pub punctuation: Paint,
+    /// Tree guide lines
+    pub guide: Paint,
+    /// Error messages and markers
+    pub error: Paint,
+}
+
+impl Theme {
+    /// A theme without any styles.
+    pub const fn plain() -> Self {
+        Theme {
+            key: Paint::new(),
+            type_name: Paint::new(),
+            string: Paint::new(),
+            number: Paint::new(),
+            boolean: Paint::new(),
+            null: Paint::new(),
+            punctuation: Paint::new(),
+            guide: Paint::new(),
+            error: Paint::new(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            key: Paint::new().fg(Color::Cyan),
+            type_name: Paint::new().bold().fg(Color::Blue),
+            string: Paint::new().fg(Color::Green),
+            number: Paint::new().fg(Color::Yellow),
+            boolean: Paint::new().fg(Color::Purple),
+            null: Paint::new().italic().fg(Color::Purple),
+            punctuation: Paint::new(),
+            guide: Paint::new().fg(Color::Fixed(8)),
+            error: Paint::new().bold().fg(Color::Red),
+        }
+    }
+}
+
+/// Pretty-prints `Debug` output with syntax highlighting, like `{:#?}` but only breaking the
+/// groups that do not fit the width.
+///
+/// The `Debug` text is re-tokenized into field names, type names, strings, numbers, booleans and
+/// punctuation, each styled by the `Theme`; `None` is styled as a null value. Brackets that fit on
+/// the rest of the line are kept on one line, others are broken with one item per line and
+/// trailing commas as `{:#?}` does. Groups nested deeper than `max_depth` are elided with `…`.
+///
+/// Any text using the `Debug` conventions can be printed with `from_debug_str`, including
+/// `{:#?}` output.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// #[derive(Debug)]
+/// struct Point { x: i32, y: i32 }
+///
+/// #[derive(Debug)]
+/// struct Shape { name: &'static str, points: Vec<Point>, closed: Option<bool> }
+///
+/// let shape = Shape { name: "triangle", points: vec![Point { x: 0, y: 0 }, Point { x: 4, y: 3 }], closed: None };
+///
+/// let pretty = PrettyDebug::new(&shape).theme(Theme::plain()).width(40);
+/// assert_eq!(pretty.to_string(), r#"Shape {
+///     name: "triangle",
+///     points: [
+///         Point { x: 0, y: 0 },
+///         Point { x: 4, y: 3 },
+///     ],
+///     closed: None,
+/// }"#);
+///
+/// let shallow = PrettyDebug::new(&shape).theme(Theme::plain()).max_depth(1);
+/// assert_eq!(shallow.to_string(), r#"Shape { name: "triangle", points: [ … ], closed: None }"#);
+///
+/// let styled = PrettyDebug::new(&Some(1.5)).to_string();
+/// assert_eq!(styled, "\x1b[1;34mSome\x1b[0m(\x1b[33m1.5\x1b[0m)");
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct PrettyDebug {
+    text: String,
+    theme: Theme,
+    indent: usize,
+    width: usize,
+    max_depth: Option<usize>,
+}
+
+impl PrettyDebug {
+    /// Creates a printer for the `Debug` output of `value`.
+    pub fn new<T: std::fmt::Debug + ?Sized>(value: &T) -> Self {
+        Self::from_debug_str(format!("{:?}", value))
+    }
+
+    /// Creates a printer for text already formatted with `Debug`.
+    pub fn from_debug_str<D: std::fmt::Display>(text: D) -> Self {
+        PrettyDebug { text: text.to_string(), theme: Theme::default(), indent: 4, width: 80, max_depth: None }
+    }
+
+    /// Sets the styles, `Theme::default()` by default.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets the number of spaces per indentation level, 4 by default.
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the width lines are kept within where possible, 80 columns by default.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Elides the contents of groups nested deeper than `depth`; a depth of 0 elides the
+    /// contents of the outermost group.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+}
+
+impl std::fmt::Display for PrettyDebug {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let tokens: Vec<Token> = Tokens { rest: &self.text }.collect();
+        let mut iter = tokens.into_iter().peekable();
+        let items = parse_items(&mut iter, None);
+        let mut printer = Printer { options: self, out: String::new(), col: 0 };
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                printer.push(", ", self.theme.punctuation);
+            }
+            printer.element(item, 0, 0, false);
+        }
+        f.write_str(&printer.out)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Key,
+    TypeName,
+    Str,
+    Number,
+    Boolean,
+    Null,
+    Punct,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: Kind,
+    text: &'a str,
+    /// Whether whitespace preceded the token in the `Debug` output
+    space: bool,
+}
+
+/// Splits `Debug` output into tokens, skipping whitespace.
+struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let trimmed = self.rest.trim_start();
+        let space = trimmed.len() != self.rest.len();
+        let s = trimmed;
+        let first = s.chars().next()?;
+        let second = s[first.len_utf8()..].chars().next();
+
+        let (kind, len) = match first {
+            '"' | '\'' => (Kind::Str, quoted_len(s, first)),
+            c if c.is_ascii_digit() || (c == '-' && second.is_some_and(|c| c.is_ascii_digit())) => (Kind::Number, number_len(s)),
+            c if c.is_alphabetic() || c == '_' => {
+                let len = s.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(s.len());
+                let after = s[len..].trim_start();
+                let kind = match &s[..len] {
+                    "true" | "false" => Kind::Boolean,
+                    "None" => Kind::Null,
+                    "inf" | "NaN" => Kind::Number,
+                    _ if after.starts_with(':') && !after.starts_with("::") => Kind::Key,
+                    _ => Kind::TypeName,
+                };
+                (kind, len)
+            },
+            '{' | '}' | '[' | ']' | '(' | ')' | ',' | ':' => (Kind::Punct, 1),
+            _ => {
+                let len = s.find(|c: char| c.is_alphanumeric() || c.is_whitespace() || "\"'{}[](),:_".contains(c)).unwrap_or(s.len());
+                (Kind::Punct, len.max(first.len_utf8()))
+            },
+        };
+
+        self.rest = &s[len..];
+        Some(Token { kind, text: &s[..len], space })
+    }
+}
+
+/// Returns the length of the string or character literal at the start of `s`.
+fn quoted_len(s: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (i, c) in s.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == quote => return i + 1,
+            _ => {},
+        }
+    }
+    s.len()
+}
+
+/// Returns the length of the number at the start of `s`, e.g. `-1.5e-7` or `0xff`.
+fn number_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut i = 1;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let exponent_sign = (b == b'-' || b == b'+') && matches!(bytes[i - 1], b'e' | b'E');
+        let decimal_point = b == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        if !(b.is_ascii_alphanumeric() || b == b'_' || exponent_sign || decimal_point) {
+            break;
+        }
+        i += 1;
+    }
+    i
+}
+
+enum Node<'a> {
+    Token(Token<'a>),
+    Group {
+        open: Token<'a>,
+        items: Vec<Vec<Node<'a>>>,
+        close: Option<Token<'a>>,
+        /// Whether the contents are padded with spaces, as in `Point { x: 1 }`
+        padded: bool,
+    },
+}
+
+impl Node<'_> {
+    fn space(&self) -> bool {
+        match self {
+            Node::Token(token) => token.space,
+            Node::Group { open, .. } => open.space,
+        }
+    }
+}
+
+type Iter<'a> = std::iter::Peekable<std::vec::IntoIter<Token<'a>>>;
+
+/// Parses comma separated items up to the bracket closing `open`, which is left in `tokens`.
+fn parse_items<'a>(tokens: &mut Iter<'a>, open: Option<char>) -> Vec<Vec<Node<'a>>> {
+    let close = match open {
+        Some('{') => Some("}"),
+        Some('[') => Some("]"),
+        Some('(') => Some(")"),
+        _ => None,
+    };
+    let mut items = Vec::new();
+    let mut item = Vec::new();
+    while let Some(token) = tokens.peek().copied() {
+        if token.kind == Kind::Punct && Some(token.text) == close {
+            break;
+        }
+        tokens.next();
+        match token.text {
+            "," if token.kind == Kind::Punct => items.push(std::mem::take(&mut item)),
+            "{" | "[" | "(" if token.kind == Kind::Punct => {
+                // Only struct braces are padded, not those of maps and sets
+                let padded = token.text == "{" && matches!(item.last(), Some(Node::Token(Token { kind: Kind::TypeName, .. })));
+                let inner = parse_items(tokens, token.text.chars().next());
+                item.push(Node::Group { open: token, items: inner, close: tokens.next(), padded });
+            },
+            _ => item.push(Node::Token(token)),
+        }
+    }
+    // A trailing comma, as in `{:#?}` output, leaves an empty item
+    if !item.is_empty() {
+        items.push(item);
+    }
+    items
+}
+
+struct Printer<'p> {
+    options: &'p PrettyDebug,
+    out: String,
+    /// Display width of the current line
+    col: usize,
+}
+
+impl Printer<'_> {
+    fn push(&mut self, text: &str, paint: Paint) {
+        self.col += text::display_width(text);
+        self.out.push_str(&paint.paint(text).to_string());
+    }
+
+    fn token(&mut self, token: &Token) {
+        let theme = &self.options.theme;
+        let paint = match token.kind {
+            Kind::Key => theme.key,
+            Kind::TypeName => theme.type_name,
+            Kind::Str => theme.string,
+            Kind::Number => theme.number,
+            Kind::Boolean => theme.boolean,
+            Kind::Null => theme.null,
+            Kind::Punct => theme.punctuation,
+        };
+        self.push(token.text, paint);
+    }
+
+    fn newline(&mut self, level: usize) {
+        self.out.push('\n');
+        self.out.push_str(&" ".repeat(level * self.options.indent));
+        self.col = level * self.options.indent;
+    }
+
+    fn element(&mut self, nodes: &[Node], level: usize, depth: usize, flat: bool) {
+        for (i, node) in nodes.iter().enumerate() {
+            // Keep the space of `Point {` and `x: 1`, but not of `Some(`
+            if i > 0 && (node.space() || matches!(nodes[i - 1], Node::Token(Token { text: ":", .. }))) {
+                self.push(" ", Paint::new());
+            }
+            match node {
+                Node::Token(token) => self.token(token),
+                Node::Group { open, items, close, padded } => {
+                    self.group(open, items, close.as_ref(), *padded, level, depth, flat);
+                },
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn group(&mut self, open: &Token, items: &[Vec<Node>], close: Option<&Token>, padded: bool, level: usize, depth: usize, flat: bool) {
+        let punctuation = self.options.theme.punctuation;
+        let pad = if padded { " " } else { "" };
+        self.token(open);
+        if !items.is_empty() {
+            if self.options.max_depth.is_some_and(|max| depth >= max) {
+                let ellipsis = Utf8::HorizontalEllipsis.to_string();
+                self.push(&format!(" {} ", ellipsis), punctuation);
+            } else if flat || self.col + flat_width(items, padded, depth + 1, self.options) + 2 <= self.options.width {
+                self.push(pad, Paint::new());
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        self.push(", ", punctuation);
+                    }
+                    self.element(item, level, depth + 1, true);
+                }
+                self.push(pad, Paint::new());
+            } else {
+                for item in items {
+                    self.newline(level + 1);
+                    self.element(item, level + 1, depth + 1, false);
+                    self.push(",", punctuation);
+                }
+                self.newline(level);
+            }
+        }
+        if let Some(close) = close {
+            self.token(close);
+        }
+    }
+}
+
+/// Returns the width of the contents of a group laid out on one line.
+fn flat_width(items: &[Vec<Node>], padded: bool, depth: usize, options: &PrettyDebug) -> usize {
+    let mut width = if padded { 2 } else { 0 } + items.len().saturating_sub(1) * 2;
+    for item in items {
+        for (i, node) in item.iter().enumerate() {
+            if i > 0 && (node.space() || matches!(item[i - 1], Node::Token(Token { text: ":", .. }))) {
+                width += 1;
+            }
+            width += match node {
+                Node::Token(token) => text::display_width(token.text),
+                Node::Group { open, items, close, padded } => {
+                    let brackets = text::display_width(open.text) + close.map_or(0, |c| text::display_width(c.text));
+                    if items.is_empty() {
+                        brackets
+                    } else if options.max_depth.is_some_and(|max| depth >= max) {
+                        brackets + 3
+                    } else {
+                        brackets + flat_width(items, *padded, depth + 1, options)
+                    }
+                },
+            };
+        }
+    }
+    width
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let kinds: Vec<_> = Tokens { rest: r#"A { b: "x\"y", c: -1.5e-3, d: 'z', e: [true, None], f: 1..3 }"# }
+            .map(|t| (t.kind, t.text))
+            .collect();
+        assert_eq!(kinds[..5], [
+            (Kind::TypeName, "A"), (Kind::Punct, "{"), (Kind::Key, "b"), (Kind::Punct, ":"), (Kind::Str, r#""x\"y""#),
+        ]);
+        assert!(kinds.contains(&(Kind::Number, "-1.5e-3")));
+        assert!(kinds.contains(&(Kind::Str, "'z'")));
+        assert!(kinds.contains(&(Kind::Null, "None")));
+        assert!(kinds.contains(&(Kind::Punct, "..")));
+    }
+
+    #[test]
+    fn test_reflows_alternate_output() {
+        let text = format!("{:#?}", (vec![1, 2], "a"));
+        let pretty = PrettyDebug::from_debug_str(text).theme(Theme::plain());
+        assert_eq!(pretty.to_string(), r#"([1, 2], "a")"#);
+        let narrow = PrettyDebug::new(&vec![(1, 2), (3, 4)]).theme(Theme::plain()).width(10).indent(2);
+        assert_eq!(narrow.to_string(), "[\n  (1, 2),\n  (3, 4),\n]");
+    }
+}
@@ -0,0 +1,355 @@
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+use crate::characters::Utf8;
+use crate::control::Control;
+use crate::style::{style, Style};
+
+/// The layout used when no template is set.
+pub const DEFAULT_TEMPLATE: &str = "{bar} {percent} {eta} {msg}";
+
+/// A progress bar redrawn in place on a terminal, or printed as periodic plain lines otherwise.
+///
+/// The layout is a template in which the following placeholders are replaced on every draw:
+///
+/// - `{bar}`: the bar itself, drawn with eighth-blocks for sub-character precision
+/// - `{percent}`: completion percentage, e.g. ` 42%`
+/// - `{pos}` and `{total}`: current position and total
+/// - `{elapsed}` and `{eta}`: time since the start and estimated time remaining, as `MM:SS`
+/// - `{rate}`: throughput in steps per second
+/// - `{msg}`: the current message
+///
+/// On a terminal every draw returns to the start of the line with a carriage return and the
+/// filled and empty parts of the bar are styled. Elsewhere (pipes, files, CI logs) a plain line is
+/// written at most once per draw interval, which defaults to one second.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let mut bar = ProgressBar::with_writer(4, Vec::new())
+///     .template("[{bar}] {pos}/{total} {msg}")
+///     .width(4)
+///     .draw_interval(std::time::Duration::ZERO);
+///
+/// bar.inc(1).unwrap();
+/// bar.set_message("half");
+/// bar.inc(1).unwrap();
+/// bar.finish().unwrap();
+///
+/// let output = String::from_utf8(bar.into_inner()).unwrap();
+/// assert_eq!(output, "\
+/// [█░░░] 1/4\n\
+/// [██░░] 2/4 half\n\
+/// [████] 4/4 half\n");
+/// ```
+///
+pub struct ProgressBar<W: Write = io::Stderr> {
+    writer: W,
+    tty: bool,
+    total: u64,
+    position: u64,
+    message: String,
+    template: String,
+    width: usize,
+    filled_style: Vec<Style>,
+    empty_style: Vec<Style>,
+    empty: Utf8,
+    start: Instant,
+    draw_interval: Option<Duration>,
+    last_draw: Option<Instant>,
+    finished: bool,
+}
+
+impl ProgressBar<io::Stderr> {
+    /// Creates a progress bar counting up to `total` that draws to standard error.
+    pub fn new(total: u64) -> Self {
+        let tty = io::stderr().is_terminal();
+        Self::with_writer(total, io::stderr()).tty(tty)
+    }
+}
+
+impl<W: Write> ProgressBar<W> {
+    /// Creates a progress bar counting up to `total` that draws to `writer`.
+    ///
+    /// The writer is treated as a non-terminal unless `tty(true)` is set.
+    pub fn with_writer(total: u64, writer: W) -> Self {
+        ProgressBar {
+            writer,
+            tty: false,
+            total,
+            position: 0,
+            message: String::new(),
+            template: DEFAULT_TEMPLATE.to_string(),
+            width: 30,
+            filled_style: Vec::new(),
+            empty_style: Vec::new(),
+            empty: Utf8::LightShade,
+            start: Instant::now(),
+            draw_interval: None,
+            last_draw: None,
+            finished: false,
+        }
+    }
+
+    /// Sets whether the writer is a terminal, which enables in-place redraws and styles.
+    pub fn tty(mut self, tty: bool) -> Self {
+        self.tty = tty;
+        self
+    }
+
+    /// Sets the layout template, see the type documentation for the available placeholders.
+    pub fn template<D: std::fmt::Display>(mut self, template: D) -> Self {
+        self.template = template.to_string();
+        self
+    }
+
+    /// Sets the width of the `{bar}` placeholder in columns.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the styles of the filled part of the bar.
+    pub fn filled_style<I: IntoIterator<Item = Style>>(mut self, styles: I) -> Self {
+        self.filled_style = styles.into_iter().collect();
+        self
+    }
+
+    /// Sets the styles of the empty part of the bar.
+    pub fn empty_style<I: IntoIterator<Item = Style>>(mut self, styles: I) -> Self {
+        self.empty_style = styles.into_iter().collect();
+        self
+    }
+
+    /// Sets the character used for the empty part of the bar, `Utf8::LightShade` (`░`) by default.
+    pub fn empty_char(mut self, empty: Utf8) -> Self {
+        self.empty = empty;
+        self
+    }
+
+    /// Sets the minimum time between two draws. Defaults to 50 milliseconds on a terminal and one
+    /// second otherwise; the first and final draws always happen.
+    pub fn draw_interval(mut self, interval: Duration) -> Self {
+        self.draw_interval = Some(interval);
+        self
+    }
+
+    /// Sets the message shown by the `{msg}` placeholder, from the next draw on.
+    pub fn set_message<D: std::fmt::Display>(&mut self, message: D) {
+        self.message = message.to_string();
+    }
+
+    /// Returns the current position.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the completed fraction, between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            return if self.finished { 1.0 } else { 0.0 };
+        }
+        (self.position as f64 / self.total as f64).min(1.0)
+    }
+
+    /// Advances the bar by `delta` steps and redraws it if the draw interval has passed.
+    pub fn inc(&mut self, delta: u64) -> io::Result<()> {
+        self.set_position(self.position.saturating_add(delta))
+    }
+
+    /// Moves the bar to `position` and redraws it if the draw interval has passed.
+    pub fn set_position(&mut self, position: u64) -> io::Result<()> {
+        self.position = position;
+        let interval = self.draw_interval.unwrap_or(if self.tty {
+            Duration::from_millis(50)
+        } else {
+            Duration::from_secs(1)
+        });
+        match self.last_draw {
+            Some(last) if last.elapsed() < interval => Ok(()),
+            _ => self.draw(),
+        }
+    }
+
+    /// Completes the bar, draws it a final time and ends its line.
+    pub fn finish(&mut self) -> io::Result<()> {
+        self.position = self.position.max(self.total);
+        self.finished = true;
+        self.draw()?;
+        if self.tty {
+            writeln!(self.writer)?;
+        }
+        self.writer.flush()
+    }
+
+    /// Replaces the message and completes the bar.
+    pub fn finish_with_message<D: std::fmt::Display>(&mut self, message: D) -> io::Result<()> {
+        self.set_message(message);
+        self.finish()
+    }
+
+    /// Returns the throughput in steps per second since the bar was created.
+    pub fn rate(&self) -> f64 {
+        let elapsed = self.start.elapsed().as_secs_f64();
+        if elapsed > 0.0 { self.position as f64 / elapsed } else { 0.0 }
+    }
+
+    /// Returns the estimated time until completion, or `None` before any progress was made or when
+    /// the estimate is too large to represent.
+    pub fn eta(&self) -> Option<Duration> {
+        let rate = self.rate();
+        if rate <= 0.0 {
+            return None;
+        }
+        let remaining = self.total.saturating_sub(self.position) as f64;
+        // Estimates beyond what a `Duration` holds are as good as unknown
+        Duration::try_from_secs_f64(remaining / rate).ok()
+    }
+
+    /// Renders the current line without any cursor control, styled only on a terminal.
+    pub fn render(&self) -> String {
+        let mut line = String::with_capacity(self.template.len() + self.width * 3);
+        let mut rest = self.template.as_str();
+        while let Some(open) = rest.find('{') {
+            line.push_str(&rest[..open]);
+            let Some(close) = rest[open..].find('}') else {
+                rest = &rest[open..];
+                break;
+            };
+            let key = &rest[open + 1..open + close];
+            match key {
+                "bar" => line.push_str(&self.render_bar()),
+                "percent" => line.push_str(&format!("{:>3}%", (self.fraction() * 100.0).floor() as u64)),
+                "pos" => line.push_str(&self.position.to_string()),
+                "total" => line.push_str(&self.total.to_string()),
+                "elapsed" => line.push_str(&format_duration(self.start.elapsed())),
+                "eta" => line.push_str(&self.eta().map_or("--:--".to_string(), format_duration)),
+                "rate" => line.push_str(&format!("{:.1}/s", self.rate())),
+                "msg" => line.push_str(&self.message),
+                _ => line.push_str(&rest[open..=open + close]),
+            }
+            rest = &rest[open + close + 1..];
+        }
+        line.push_str(rest);
+        line
+    }
+
+    fn render_bar(&self) -> String {
+        // Rounded down like `{percent}`, so the bar is only full once the work is done
+        let eighths = (self.fraction() * self.width as f64 * 8.0).floor() as usize;
+        let full = eighths / 8;
+        let mut filled = Utf8::FullBlock.repeat(full);
+        let mut used = full;
+        let partial = eighths % 8;
+        if partial > 0 {
+            filled.push_str(&Utf8::LEFT_EIGHTHS[partial - 1].to_string());
+            used += 1;
+        }
+        let empty = self.empty.repeat(self.width.saturating_sub(used));
+
+        let paint = |styles: &[Style], s: String| {
+            if !self.tty || styles.is_empty() || s.is_empty() { s } else { style(styles.iter().copied(), s) }
+        };
+        format!("{}{}", paint(&self.filled_style, filled), paint(&self.empty_style, empty))
+    }
+
+    /// Draws the bar immediately, regardless of the draw interval.
+    pub fn draw(&mut self) -> io::Result<()> {
+        let line = self.render();
+        if self.tty {
+            write!(self.writer, "\r{}{}", line, Control::EraseLineToEnd)?;
+        } else {
+            writeln!(self.writer, "{}", line.trim_end())?;
+        }
+        self.last_draw = Some(Instant::now());
+        self.writer.flush()
+    }
+
+    /// Consumes the bar and returns its writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Formats a duration as `MM:SS`, or `H:MM:SS` from one hour on.
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    } else {
+        format!("{:02}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eighth_precision() {
+        let mut bar = ProgressBar::with_writer(16, Vec::new()).template("{bar}").width(2);
+        bar.position = 12;
+        assert_eq!(bar.render(), "█▌");
+        bar.position = 1;
+        assert_eq!(bar.render(), "▏░");
+        bar.template = "{bar} {unknown} {".to_string();
+        assert_eq!(bar.render(), "▏░ {unknown} {");
+    }
+
+    #[test]
+    fn test_full_only_at_completion() {
+        let mut bar = ProgressBar::with_writer(1000, Vec::new()).template("{bar} {percent}").width(10);
+        bar.position = 999;
+        assert_eq!(bar.render(), "█████████▉  99%");
+        bar.position = 1000;
+        assert_eq!(bar.render(), "██████████ 100%");
+    }
+
+    #[test]
+    fn test_tty_redraw_and_styles() {
+        let mut bar = ProgressBar::with_writer(2, Vec::new())
+            .tty(true)
+            .template("{bar} {percent}")
+            .width(2)
+            .filled_style([Style::FGGreen])
+            .draw_interval(Duration::ZERO);
+        bar.inc(1).unwrap();
+        bar.finish().unwrap();
+        let output = String::from_utf8(bar.into_inner()).unwrap();
+        assert_eq!(output, concat!(
+            "\r\x1b[32m█\x1b[0m░  50%\x1b[K",
+            "\r\x1b[32m██\x1b[0m 100%\x1b[K\n",
+        ));
+    }
+
+    #[test]
+    fn test_non_tty_throttles() {
+        let mut bar = ProgressBar::with_writer(100, Vec::new()).template("{pos}");
+        for _ in 0..100 {
+            bar.inc(1).unwrap();
+        }
+        bar.finish().unwrap();
+        assert_eq!(String::from_utf8(bar.into_inner()).unwrap(), "1\n100\n");
+    }
+
+    #[test]
+    fn test_eta_out_of_range() {
+        let mut bar = ProgressBar::with_writer(u64::MAX, Vec::new()).template("{eta}");
+        bar.start -= Duration::from_secs(1);
+        bar.position = 1;
+        assert_eq!(bar.eta(), None);
+        assert_eq!(bar.render(), "--:--");
+        bar.total = 3;
+        assert!(bar.eta().is_some());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(75)), "01:15");
+        assert_eq!(format_duration(Duration::from_secs(3725)), "1:02:05");
+    }
+}
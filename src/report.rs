@@ -0,0 +1,545 @@
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use crate::characters::{charset, Charset, Utf8};
+use crate::style::{Color, Paint};
+use crate::text;
+
+/// The severity of a `Report`, or the kind of one of its footer lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Level {
+    Error,
+    Warning,
+    Note,
+    Help,
+}
+
+impl Level {
+    /// Returns the style of the level's name and primary labels.
+    pub const fn paint(&self) -> Paint {
+        match self {
+            Level::Error => Paint::new().bold().fg(Color::Red),
+            Level::Warning => Paint::new().bold().fg(Color::Yellow),
+            Level::Note => Paint::new().bold().fg(Color::Green),
+            Level::Help => Paint::new().bold().fg(Color::Cyan),
+        }
+    }
+}
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Level::Error => "error",
+            Level::Warning => "warning",
+            Level::Note => "note",
+            Level::Help => "help",
+        })
+    }
+}
+
+/// A message attached to a byte range of the source.
+#[derive(Debug, Clone)]
+struct Label {
+    span: Range<usize>,
+    message: String,
+    primary: bool,
+}
+
+/// A compiler-style diagnostic: a header, the location, the labeled source lines and footer notes.
+///
+/// Labels point at byte ranges of the source. Primary labels are underlined with `^` in the color
+/// of the level and secondary labels with `-`; the first primary label gives the location in the
+/// header. Spans covering several lines are drawn with a line in the margin from the start to the
+/// end of the span. Lines between labels are skipped when more than one of them would be shown.
+///
+/// The gutter and span lines use `Utf8` box-drawing characters, which follow the global charset or
+/// the one given with `charset`; `color(false)` removes every style for plain output.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let source = "[server]\nport = \"80\"\nhost = \"localhost\"\n";
+/// let report = Report::error("mismatched types")
+///     .code("E0308")
+///     .path("config.toml")
+///     .source(source)
+///     .label(16..20, "expected an integer")
+///     .secondary_label(0..8, "in this table")
+///     .help("remove the quotes")
+///     .color(false);
+///
+/// assert_eq!(report.to_string(), "\
+/// error[E0308]: mismatched types
+///  --> config.toml:2:8
+///   │
+/// 1 │ [server]
+///   │ -------- in this table
+/// 2 │ port = \"80\"
+///   │        ^^^^ expected an integer
+///   │
+///   = help: remove the quotes
+/// ");
+///
+/// let multiline = Report::warning("unused table")
+///     .source(source)
+///     .label(0..20, "never read")
+///     .charset(Charset::Ascii)
+///     .color(false);
+///
+/// assert_eq!(multiline.to_string(), "\
+/// warning: unused table
+///  --> 1:1
+///   |
+/// 1 |   [server]
+///   | +-^
+/// 2 | | port = \"80\"
+///   | +-----------^ never read
+///   |
+/// ");
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct Report {
+    level: Level,
+    code: Option<String>,
+    message: String,
+    path: Option<String>,
+    source: String,
+    labels: Vec<Label>,
+    footers: Vec<(Level, String)>,
+    color: bool,
+    charset: Option<Charset>,
+}
+
+impl Report {
+    /// Creates a report of `level` with the headline `message`.
+    pub fn new<D: std::fmt::Display>(level: Level, message: D) -> Self {
+        Report {
+            level,
+            code: None,
+            message: message.to_string(),
+            path: None,
+            source: String::new(),
+            labels: Vec::new(),
+            footers: Vec::new(),
+            color: true,
+            charset: None,
+        }
+    }
+
+    /// Creates an error report.
+    pub fn error<D: std::fmt::Display>(message: D) -> Self {
+        Self::new(Level::Error, message)
+    }
+
+    /// Creates a warning report.
+    pub fn warning<D: std::fmt::Display>(message: D) -> Self {
+        Self::new(Level::Warning, message)
+    }
+
+    /// Sets a code shown after the level, as in `error[E0308]`.
+    pub fn code<D: std::fmt::Display>(mut self, code: D) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    /// Sets the file name shown before the line and column.
+    pub fn path<D: std::fmt::Display>(mut self, path: D) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    /// Sets the source text the labels point into.
+    pub fn source<D: std::fmt::Display>(mut self, source: D) -> Self {
+        self.source = source.to_string();
+        self
+    }
+
+    /// Adds a primary label on the byte range `span` of the source. The message may be empty.
+    pub fn label<D: std::fmt::Display>(mut self, span: Range<usize>, message: D) -> Self {
+        self.labels.push(Label { span, message: message.to_string(), primary: true });
+        self
+    }
+
+    /// Adds a secondary label on the byte range `span` of the source. The message may be empty.
+    pub fn secondary_label<D: std::fmt::Display>(mut self, span: Range<usize>, message: D) -> Self {
+        self.labels.push(Label { span, message: message.to_string(), primary: false });
+        self
+    }
+
+    /// Adds a `= note:` line after the source.
+    pub fn note<D: std::fmt::Display>(mut self, message: D) -> Self {
+        self.footers.push((Level::Note, message.to_string()));
+        self
+    }
+
+    /// Adds a `= help:` line after the source.
+    pub fn help<D: std::fmt::Display>(mut self, message: D) -> Self {
+        self.footers.push((Level::Help, message.to_string()));
+        self
+    }
+
+    /// Sets whether the report is styled, `true` by default.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Draws the report with `charset` instead of the global charset.
+    pub fn charset(mut self, charset: Charset) -> Self {
+        self.charset = Some(charset);
+        self
+    }
+
+    fn paint(&self, paint: Paint) -> Paint {
+        if self.color { paint } else { Paint::new() }
+    }
+
+    /// Resolves the labels to lines and display columns of the source.
+    fn markers(&self) -> Vec<Marker<'_>> {
+        let source = self.source.as_str();
+        let line_starts: Vec<usize> = std::iter::once(0).chain(source.match_indices('\n').map(|(i, _)| i + 1)).collect();
+        let boundary = |mut byte: usize| {
+            byte = byte.min(source.len());
+            while !source.is_char_boundary(byte) {
+                byte -= 1;
+            }
+            byte
+        };
+        // Returns the line, display column and 1-based character column of a byte offset
+        let locate = |byte: usize| {
+            let line = line_starts.partition_point(|&start| start <= byte) - 1;
+            let prefix = &source[line_starts[line]..byte];
+            (line, expanded_width(prefix), prefix.chars().count() + 1)
+        };
+
+        let mut markers: Vec<Marker> = self
+            .labels
+            .iter()
+            .map(|label| {
+                let start = boundary(label.span.start);
+                let end = boundary(label.span.end.max(start));
+                let (start_line, start_col, column) = locate(start);
+                let last = source[..end].char_indices().next_back().map_or(start, |(i, _)| i.max(start));
+                let (end_line, last_col, _) = locate(last);
+                let end_col = if end_line > start_line {
+                    last_col
+                } else if end > start {
+                    start_col + expanded_width(&source[start..end]).max(1)
+                } else {
+                    start_col + 1
+                };
+                Marker { start_line, start_col, end_line, end_col, column, lane: 0, label }
+            })
+            .collect();
+
+        // Spans starting first get the lanes furthest from the source
+        let mut multiline: Vec<usize> = (0..markers.len()).filter(|&i| markers[i].is_multiline()).collect();
+        multiline.sort_by_key(|&i| (markers[i].start_line, markers[i].start_col));
+        for (lane, i) in multiline.into_iter().enumerate() {
+            markers[i].lane = lane;
+        }
+        markers
+    }
+}
+
+/// A label resolved to the source lines, in display columns with tabs expanded.
+struct Marker<'a> {
+    start_line: usize,
+    start_col: usize,
+    end_line: usize,
+    /// The column after the span on a single line, or of its last character when multiline
+    end_col: usize,
+    /// The 1-based character column of the start, for the header
+    column: usize,
+    /// The margin lane of a multiline span
+    lane: usize,
+    label: &'a Label,
+}
+
+impl Marker<'_> {
+    fn is_multiline(&self) -> bool {
+        self.end_line > self.start_line
+    }
+}
+
+const TAB: &str = "    ";
+
+fn expanded_width(s: &str) -> usize {
+    text::display_width(&s.replace('\t', TAB))
+}
+
+/// A line of styled segments placed at display columns.
+#[derive(Default)]
+struct Row {
+    segments: Vec<(Paint, String)>,
+    width: usize,
+}
+
+impl Row {
+    fn push(&mut self, text: &str, paint: Paint) {
+        self.width += text::display_width(text);
+        match self.segments.last_mut() {
+            Some((last, segment)) if *last == paint => segment.push_str(text),
+            _ => self.segments.push((paint, text.to_string())),
+        }
+    }
+
+    /// Pushes `text` at `col`, padding with spaces up to it.
+    fn put(&mut self, col: usize, text: &str, paint: Paint) {
+        if col > self.width {
+            self.push(&" ".repeat(col - self.width), Paint::new());
+        }
+        self.push(text, paint);
+    }
+
+    fn is_blank(&self) -> bool {
+        self.segments.iter().all(|(_, s)| s.trim().is_empty())
+    }
+
+    fn finish(mut self) -> String {
+        while self.segments.last().is_some_and(|(_, s)| s.trim().is_empty()) {
+            self.segments.pop();
+        }
+        let mut out = String::new();
+        let count = self.segments.len();
+        for (i, (paint, segment)) in self.segments.iter().enumerate() {
+            let segment = if i + 1 == count { segment.trim_end() } else { segment };
+            out.push_str(&paint.paint(segment).to_string());
+        }
+        out
+    }
+}
+
+impl std::fmt::Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let charset = self.charset.unwrap_or_else(charset);
+        let glyph = |c: Utf8| c.with_charset(charset).to_string();
+        let (vbar, hbar) = (glyph(Utf8::VPipeSlim), glyph(Utf8::BoxDrawingsLightHorizontal));
+        let (top, bottom) = (glyph(Utf8::BoxDrawingsLightArcDownAndRight), glyph(Utf8::BoxDrawingsLightArcUpAndRight));
+        let level_paint = self.paint(self.level.paint());
+        let gutter_paint = self.paint(Paint::new().bold().fg(Color::Blue));
+        let bold = self.paint(Paint::new().bold());
+        let marker_paint = |marker: &Marker| if marker.label.primary { level_paint } else { gutter_paint };
+        let marker_char = |marker: &Marker| if marker.label.primary { "^" } else { "-" };
+
+        let code = self.code.as_ref().map(|code| format!("[{}]", code)).unwrap_or_default();
+        writeln!(f, "{}{}", level_paint.paint(&format!("{}{}", self.level, code)), bold.paint(&format!(": {}", self.message)))?;
+
+        let markers = self.markers();
+        let mut shown = BTreeSet::new();
+        for marker in &markers {
+            shown.insert(marker.start_line);
+            shown.insert(marker.end_line);
+        }
+        let width = shown.last().map_or(0, |last| (last + 1).to_string().len());
+        let lines: Vec<String> = self.source.split('\n').map(|line| line.trim_end_matches('\r').replace('\t', TAB)).collect();
+        let lanes = markers.iter().filter(|m| m.is_multiline()).count();
+
+        let write_row = |f: &mut std::fmt::Formatter, number: Option<usize>, row: Row| {
+            let number = number.map(|n| n.to_string()).unwrap_or_default();
+            let gutter = gutter_paint.paint(&format!("{:>width$} {}", number, vbar, width = width)).to_string();
+            if row.is_blank() {
+                writeln!(f, "{}", gutter)
+            } else {
+                writeln!(f, "{} {}", gutter, row.finish())
+            }
+        };
+        // Draws the margin lanes, `cell` returning the two columns of each lane that is not blank
+        let margin = |row: &mut Row, cell: &dyn Fn(&Marker) -> Option<(String, Paint)>| {
+            for lane in 0..lanes {
+                let marker = markers.iter().find(|m| m.is_multiline() && m.lane == lane).expect("every lane has a span");
+                match cell(marker) {
+                    Some((text, paint)) => row.put(lane * 2, &text, paint),
+                    None => row.put(lane * 2 + 2, "", Paint::new()),
+                }
+            }
+        };
+        let open = |marker: &Marker, open: bool| open.then(|| (format!("{} ", vbar), marker_paint(marker)));
+
+        if let Some(first) = markers.iter().find(|m| m.label.primary).or(markers.first()) {
+            let path = self.path.as_ref().map(|path| format!("{}:", path)).unwrap_or_default();
+            writeln!(f, "{:width$}{} {}{}:{}", "", gutter_paint.paint("-->"), path, first.start_line + 1, first.column, width = width)?;
+            write_row(f, None, Row::default())?;
+        }
+
+        let mut previous: Option<usize> = None;
+        let mut rows: Vec<usize> = Vec::new();
+        for &line in &shown {
+            match previous {
+                Some(previous) if line == previous + 2 => rows.push(previous + 1),
+                Some(previous) if line > previous + 2 => rows.push(usize::MAX),
+                _ => {},
+            }
+            rows.push(line);
+            previous = Some(line);
+        }
+
+        for line in rows {
+            if line == usize::MAX {
+                writeln!(f, "{}", gutter_paint.paint("..."))?;
+                continue;
+            }
+
+            let mut row = Row::default();
+            margin(&mut row, &|m| open(m, m.start_line < line && line <= m.end_line));
+            row.put(lanes * 2, lines.get(line).map_or("", String::as_str), Paint::new());
+            write_row(f, Some(line + 1), row)?;
+
+            // Underlines of the spans on this line, with the message of the rightmost inline
+            let mut single: Vec<&Marker> = markers.iter().filter(|m| !m.is_multiline() && m.start_line == line).collect();
+            single.sort_by_key(|m| (m.start_col, m.label.primary));
+            let passing = |m: &Marker| open(m, m.start_line < line && line <= m.end_line);
+            if !single.is_empty() {
+                let mut cells: Vec<Option<&Marker>> = Vec::new();
+                for marker in &single {
+                    cells.resize(cells.len().max(marker.end_col), None);
+                    for cell in &mut cells[marker.start_col..marker.end_col] {
+                        if !cell.is_some_and(|m| m.label.primary) {
+                            *cell = Some(marker);
+                        }
+                    }
+                }
+                let mut row = Row::default();
+                margin(&mut row, &passing);
+                for (col, cell) in cells.iter().enumerate() {
+                    if let Some(marker) = cell {
+                        row.put(lanes * 2 + col, marker_char(marker), marker_paint(marker));
+                    }
+                }
+                let (inline, rest) = single.split_last().expect("not empty");
+                if !inline.label.message.is_empty() {
+                    row.put(row.width + 1, &inline.label.message, marker_paint(inline));
+                }
+                write_row(f, None, row)?;
+
+                let rest: Vec<&&Marker> = rest.iter().rev().filter(|m| !m.label.message.is_empty()).collect();
+                if !rest.is_empty() {
+                    let mut row = Row::default();
+                    margin(&mut row, &passing);
+                    for marker in rest.iter().rev() {
+                        row.put(lanes * 2 + marker.start_col, &vbar, marker_paint(marker));
+                    }
+                    write_row(f, None, row)?;
+                }
+                for (i, marker) in rest.iter().enumerate() {
+                    let mut row = Row::default();
+                    margin(&mut row, &passing);
+                    for left in rest[i + 1..].iter().rev() {
+                        row.put(lanes * 2 + left.start_col, &vbar, marker_paint(left));
+                    }
+                    row.put(lanes * 2 + marker.start_col, &marker.label.message, marker_paint(marker));
+                    write_row(f, None, row)?;
+                }
+            }
+
+            // Ends of multiline spans, then starts, each on its own row
+            let mut ends: Vec<&Marker> = markers.iter().filter(|m| m.is_multiline() && m.end_line == line).collect();
+            ends.sort_by_key(|m| m.lane);
+            for end in ends {
+                let mut row = Row::default();
+                margin(&mut row, &|m| match m.lane {
+                    lane if lane < end.lane => open(m, m.start_line < line && line < m.end_line),
+                    lane if lane == end.lane => Some((format!("{}{}", bottom, hbar), marker_paint(m))),
+                    _ => Some((hbar.repeat(2), marker_paint(end))),
+                });
+                row.push(&hbar.repeat(end.end_col), marker_paint(end));
+                row.push(marker_char(end), marker_paint(end));
+                if !end.label.message.is_empty() {
+                    row.push(&format!(" {}", end.label.message), marker_paint(end));
+                }
+                write_row(f, None, row)?;
+            }
+            let mut starts: Vec<&Marker> = markers.iter().filter(|m| m.is_multiline() && m.start_line == line).collect();
+            starts.sort_by_key(|m| m.lane);
+            for start in starts {
+                let mut row = Row::default();
+                margin(&mut row, &|m| match m.lane {
+                    lane if lane < start.lane => open(m, m.start_line <= line && line < m.end_line),
+                    lane if lane == start.lane => Some((format!("{}{}", top, hbar), marker_paint(m))),
+                    _ => Some((hbar.repeat(2), marker_paint(start))),
+                });
+                row.push(&hbar.repeat(start.start_col), marker_paint(start));
+                row.push(marker_char(start), marker_paint(start));
+                write_row(f, None, row)?;
+            }
+        }
+
+        if !markers.is_empty() {
+            write_row(f, None, Row::default())?;
+        }
+        for (level, message) in &self.footers {
+            // Continuation lines are aligned with the start of the message
+            let label = format!("= {}:", level);
+            let indent = format!("\n{}", " ".repeat(width + label.len() + 2));
+            writeln!(f, "{:width$} {} {}", "", bold.paint(&label), message.replace('\n', &indent), width = width)?;
+        }
+        Ok(())
+    }
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_several_labels_on_a_line() {
+        let report = Report::error("conflicting keys")
+            .source("name = 1\tname = 2\tname = 3")
+            .label(9..13, "second")
+            .secondary_label(0..4, "first")
+            .secondary_label(18..22, "")
+            .note("keys must be unique\nwithin a table")
+            .color(false)
+            .charset(Charset::Unicode);
+        assert_eq!(report.to_string(), "\
+error: conflicting keys
+ --> 1:10
+  │
+1 │ name = 1    name = 2    name = 3
+  │ ----        ^^^^        ----
+  │ │           │
+  │ │           second
+  │ first
+  │
+  = note: keys must be unique
+          within a table
+");
+    }
+
+    #[test]
+    fn test_nested_multiline_spans_and_elision() {
+        let source = "a {\n  b {\n    c\n  }\n}\n\n\n\nz\n";
+        let report = Report::error("unbalanced")
+            .source(source)
+            .label(2..21, "outer")
+            .secondary_label(8..19, "inner")
+            .secondary_label(25..26, "here")
+            .color(false)
+            .charset(Charset::Unicode);
+        assert_eq!(report.to_string(), "\
+error: unbalanced
+ --> 1:3
+  │
+1 │     a {
+  │ ╭─────^
+2 │ │     b {
+  │ │ ╭─────-
+3 │ │ │     c
+4 │ │ │   }
+  │ │ ╰───- inner
+5 │ │   }
+  │ ╰───^ outer
+...
+9 │     z
+  │     - here
+  │
+");
+        let styled = Report::warning("w").source("x").label(0..1, "").to_string();
+        assert!(styled.contains("\x1b[1;33m^\x1b[0m"));
+    }
+}
@@ -0,0 +1,340 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::characters::{charset, Charset, Utf8};
+use crate::control::Control;
+use crate::style::{style, Style};
+
+/// The built-in spinner animations.
+///
+/// Every animation except `Line` uses non-ASCII characters; while the global charset is
+/// `Charset::Ascii` spinners draw the `Line` frames instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Frames {
+    /// `⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏`
+    Dots,
+    /// `-\|/`
+    Line,
+    /// `◜◠◝◞◡◟`
+    Arc,
+    /// `⣾⣽⣻⢿⡿⣟⣯⣷`
+    Braille,
+    /// `[=   ]` bouncing between the brackets
+    BouncingBar,
+    /// `˂˄˃˅`, the `ModLetter*Arrowhead` characters rotating clockwise
+    Arrowheads,
+    /// `←↖↑↗→↘↓↙`
+    Arrows,
+    /// `◐◓◑◒`
+    CircleHalves,
+    /// `◴◷◶◵`
+    CircleQuarters,
+    /// `◰◳◲◱`
+    SquareQuarters,
+    /// `▁▂▃▄▅▆▇█▇▆▅▄▃▂`
+    Grow,
+}
+
+impl Frames {
+    /// Returns the frames of the animation.
+    pub const fn frames(&self) -> &'static [&'static str] {
+        match self {
+            Frames::Dots => &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            Frames::Line => &["-", "\\", "|", "/"],
+            Frames::Arc => &["◜", "◠", "◝", "◞", "◡", "◟"],
+            Frames::Braille => &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"],
+            Frames::BouncingBar => &[
+                "[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]",
+                "[   =]", "[    ]", "[   =]", "[  ==]", "[ ===]", "[====]",
+                "[=== ]", "[==  ]", "[=   ]",
+            ],
+            Frames::Arrowheads => &["˂", "˄", "˃", "˅"],
+            Frames::Arrows => &["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"],
+            Frames::CircleHalves => &["◐", "◓", "◑", "◒"],
+            Frames::CircleQuarters => &["◴", "◷", "◶", "◵"],
+            Frames::SquareQuarters => &["◰", "◳", "◲", "◱"],
+            Frames::Grow => &["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█", "▇", "▆", "▅", "▄", "▃", "▂"],
+        }
+    }
+
+    /// Returns the time between frames the animation is designed for.
+    pub const fn interval(&self) -> Duration {
+        match self {
+            Frames::Dots | Frames::Braille | Frames::BouncingBar => Duration::from_millis(80),
+            Frames::Line | Frames::Arc | Frames::Grow => Duration::from_millis(100),
+            Frames::Arrowheads | Frames::Arrows => Duration::from_millis(120),
+            Frames::CircleHalves | Frames::CircleQuarters | Frames::SquareQuarters => Duration::from_millis(150),
+        }
+    }
+}
+
+/// An animated indicator for work of unknown length, followed by a message.
+///
+/// The spinner advances one frame per `tick()`, either called manually or from a background
+/// thread started with `start()`. It ends with a success or failure glyph (`✔`/`✘` by default,
+/// green and red) in place of the animation. Every method takes `&self`, so the message can be
+/// updated while the background thread is ticking.
+///
+/// On a terminal the line is redrawn in place; elsewhere only the final line is written.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let spinner = Spinner::with_writer(Vec::new(), Frames::Line)
+///     .tty(true)
+///     .message("building");
+/// spinner.tick().unwrap();
+/// spinner.tick().unwrap();
+/// spinner.finish_success("built").unwrap();
+///
+/// let output = String::from_utf8(spinner.into_inner().unwrap()).unwrap();
+/// assert_eq!(output, "\r- building\x1b[K\r\\ building\x1b[K\r\x1b[32m✔\x1b[0m built\x1b[K\n");
+/// ```
+///
+pub struct Spinner<W: Write + Send + 'static = io::Stderr> {
+    shared: Arc<Shared<W>>,
+    thread: Mutex<Option<JoinHandle<()>>>,
+}
+
+struct Shared<W: Write> {
+    state: Mutex<State<W>>,
+    running: AtomicBool,
+}
+
+struct State<W: Write> {
+    writer: W,
+    tty: bool,
+    frames: Vec<String>,
+    frame: usize,
+    style: Vec<Style>,
+    message: String,
+    success: (String, Vec<Style>),
+    failure: (String, Vec<Style>),
+    finished: bool,
+}
+
+impl Spinner<io::Stderr> {
+    /// Creates a spinner drawing `frames` to standard error.
+    pub fn new(frames: Frames) -> Self {
+        let tty = io::stderr().is_terminal();
+        Self::with_writer(io::stderr(), frames).tty(tty)
+    }
+}
+
+impl<W: Write + Send + 'static> Spinner<W> {
+    /// Creates a spinner drawing `frames` to `writer`.
+    ///
+    /// The writer is treated as a non-terminal unless `tty(true)` is set.
+    pub fn with_writer(writer: W, frames: Frames) -> Self {
+        let state = State {
+            writer,
+            tty: false,
+            frames: frames.frames().iter().map(|f| f.to_string()).collect(),
+            frame: 0,
+            style: Vec::new(),
+            message: String::new(),
+            success: (Utf8::HeavyCheckMark.to_string(), vec![Style::FGGreen]),
+            failure: (Utf8::HeavyBallotX.to_string(), vec![Style::FGRed]),
+            finished: false,
+        };
+        Spinner {
+            shared: Arc::new(Shared { state: Mutex::new(state), running: AtomicBool::new(false) }),
+            thread: Mutex::new(None),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State<W>> {
+        self.shared.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Sets whether the writer is a terminal, which enables the animation and styles.
+    pub fn tty(self, tty: bool) -> Self {
+        self.lock().tty = tty;
+        self
+    }
+
+    /// Replaces the animation with custom frames.
+    pub fn custom_frames<I>(self, frames: I) -> Self
+    where I: IntoIterator, I::Item: std::fmt::Display
+    {
+        self.lock().frames = frames.into_iter().map(|f| f.to_string()).collect();
+        self
+    }
+
+    /// Sets the styles of the animation frames.
+    pub fn style<I: IntoIterator<Item = Style>>(self, styles: I) -> Self {
+        self.lock().style = styles.into_iter().collect();
+        self
+    }
+
+    /// Sets the message shown after the animation.
+    pub fn message<D: std::fmt::Display>(self, message: D) -> Self {
+        self.set_message(message);
+        self
+    }
+
+    /// Sets the glyph and styles shown by `finish_success`.
+    pub fn success_glyph<D, I>(self, glyph: D, styles: I) -> Self
+    where D: std::fmt::Display, I: IntoIterator<Item = Style>
+    {
+        self.lock().success = (glyph.to_string(), styles.into_iter().collect());
+        self
+    }
+
+    /// Sets the glyph and styles shown by `finish_failure`.
+    pub fn failure_glyph<D, I>(self, glyph: D, styles: I) -> Self
+    where D: std::fmt::Display, I: IntoIterator<Item = Style>
+    {
+        self.lock().failure = (glyph.to_string(), styles.into_iter().collect());
+        self
+    }
+
+    /// Replaces the message, shown from the next tick on.
+    pub fn set_message<D: std::fmt::Display>(&self, message: D) {
+        self.lock().message = message.to_string();
+    }
+
+    /// Draws the next frame.
+    pub fn tick(&self) -> io::Result<()> {
+        self.lock().tick()
+    }
+
+    /// Starts ticking from a background thread every `interval` until the spinner is finished or
+    /// dropped. Does nothing if it is already ticking.
+    pub fn start(&self, interval: Duration) {
+        if self.shared.running.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let shared = Arc::clone(&self.shared);
+        let handle = thread::spawn(move || {
+            while shared.running.load(Ordering::SeqCst) {
+                let _ = shared.state.lock().unwrap_or_else(|e| e.into_inner()).tick();
+                thread::park_timeout(interval);
+            }
+        });
+        *self.thread.lock().unwrap_or_else(|e| e.into_inner()) = Some(handle);
+    }
+
+    /// Stops the background thread, if any, and waits for it to exit.
+    pub fn stop(&self) {
+        self.shared.running.store(false, Ordering::SeqCst);
+        if let Some(handle) = self.thread.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+
+    /// Stops the spinner and replaces it with the success glyph followed by `message`.
+    pub fn finish_success<D: std::fmt::Display>(&self, message: D) -> io::Result<()> {
+        let (glyph, styles) = self.lock().success.clone();
+        self.finish_with(glyph, styles, message)
+    }
+
+    /// Stops the spinner and replaces it with the failure glyph followed by `message`.
+    pub fn finish_failure<D: std::fmt::Display>(&self, message: D) -> io::Result<()> {
+        let (glyph, styles) = self.lock().failure.clone();
+        self.finish_with(glyph, styles, message)
+    }
+
+    /// Stops the spinner and replaces it with `glyph` in `styles` followed by `message`.
+    pub fn finish_with<G, I, D>(&self, glyph: G, styles: I, message: D) -> io::Result<()>
+    where G: std::fmt::Display, I: IntoIterator<Item = Style>, D: std::fmt::Display
+    {
+        self.stop();
+        let mut state = self.lock();
+        state.finished = true;
+        let styles: Vec<Style> = styles.into_iter().collect();
+        let glyph = if state.tty && !styles.is_empty() { style(styles, glyph) } else { glyph.to_string() };
+        let line = format!("{} {}", glyph, message);
+        if state.tty {
+            write!(state.writer, "\r{}{}\n", line, Control::EraseLineToEnd)?;
+        } else {
+            writeln!(state.writer, "{}", line)?;
+        }
+        state.writer.flush()
+    }
+
+    /// Stops the spinner and returns its writer, or `None` while other references to it remain.
+    pub fn into_inner(self) -> Option<W> {
+        let shared = Arc::clone(&self.shared);
+        // Dropping the spinner stops the background thread and releases its reference
+        drop(self);
+        Arc::try_unwrap(shared).ok()
+            .map(|s| s.state.into_inner().unwrap_or_else(|e| e.into_inner()).writer)
+    }
+}
+
+impl<W: Write> State<W> {
+    fn tick(&mut self) -> io::Result<()> {
+        if !self.tty || self.finished || self.frames.is_empty() {
+            return Ok(());
+        }
+        let mut frame = self.frames[self.frame % self.frames.len()].as_str();
+        if charset() == Charset::Ascii && !frame.is_ascii() {
+            let line = Frames::Line.frames();
+            frame = line[self.frame % line.len()];
+        }
+        self.frame = self.frame.wrapping_add(1);
+
+        let frame = if self.style.is_empty() { frame.to_string() } else { style(self.style.iter().copied(), frame) };
+        if self.message.is_empty() {
+            write!(self.writer, "\r{}{}", frame, Control::EraseLineToEnd)?;
+        } else {
+            write!(self.writer, "\r{} {}{}", frame, self.message, Control::EraseLineToEnd)?;
+        }
+        self.writer.flush()
+    }
+}
+
+impl<W: Write + Send + 'static> Drop for Spinner<W> {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_frames_and_failure() {
+        let spinner = Spinner::with_writer(Vec::new(), Frames::Dots)
+            .tty(true)
+            .custom_frames(["a", "b"])
+            .style([Style::Bold])
+            .failure_glyph("!", []);
+        for _ in 0..3 {
+            spinner.tick().unwrap();
+        }
+        spinner.finish_failure("oops").unwrap();
+        spinner.tick().unwrap();
+        let output = String::from_utf8(spinner.into_inner().unwrap()).unwrap();
+        assert_eq!(output, "\r\x1b[1ma\x1b[0m\x1b[K\r\x1b[1mb\x1b[0m\x1b[K\r\x1b[1ma\x1b[0m\x1b[K\r! oops\x1b[K\n");
+    }
+
+    #[test]
+    fn test_background_ticks() {
+        let spinner = Spinner::with_writer(Vec::new(), Frames::Arrowheads).tty(true);
+        spinner.start(Duration::from_millis(1));
+        thread::sleep(Duration::from_millis(20));
+        spinner.finish_success("done").unwrap();
+        let output = String::from_utf8(spinner.into_inner().unwrap()).unwrap();
+        assert!(output.starts_with("\r˂\x1b[K"));
+        assert!(output.ends_with("\r\x1b[32m✔\x1b[0m done\x1b[K\n"));
+    }
+
+    #[test]
+    fn test_non_tty_prints_only_final_line() {
+        let spinner = Spinner::with_writer(Vec::new(), Frames::Braille).message("working");
+        spinner.tick().unwrap();
+        spinner.finish_success("ok").unwrap();
+        assert_eq!(String::from_utf8(spinner.into_inner().unwrap()).unwrap(), "✔ ok\n");
+    }
+}
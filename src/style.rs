@@ -25,7 +25,7 @@
 /// println!("\x1b[{}mBold Text\x1b[0m", text_style);
 /// ```
 ///
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Style {
     Reset       = 0,
     Bold        = 1,
@@ -53,9 +53,7 @@ pub enum Style {
 
 impl std::fmt::Display for Style {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match *self {
-            _ => write!(f, "{}", *self as u64)
-        }
+        write!(f, "{}", *self as u64)
     }
 }
 
@@ -84,17 +82,17 @@ impl std::fmt::Display for Style {
 ///
 pub fn as_rgb<D: std::fmt::Display>(value: D) -> (u8, u8, u8) {
     let v = &value.to_string();
-    let hex = if v.starts_with('#') { &v[1..] } else { v };
+    let hex = v.strip_prefix('#').unwrap_or(v);
 
     match hex.len() {
         6 => {
             let r = u8::from_str_radix(&hex[0..2], 16).unwrap_or(0);
             let g = u8::from_str_radix(&hex[2..4], 16).unwrap_or(0);
             let b = u8::from_str_radix(&hex[4..6], 16).unwrap_or(0);
-            return (r, g, b);
+            (r, g, b)
         },
-        _ => return (0, 0, 0),
-    };
+        _ => (0, 0, 0),
+    }
 }
 
 /// An error encountered while converting a hexadecimal string to an RGB color.
@@ -129,6 +127,38 @@ impl std::fmt::Display for HexError {
     }
 }
 
+/// Converts a color value of `style!` and `paint!` given as an integer expression, panicking when
+/// it does not fit in a `u8`. Literal values are checked at compile time instead.
+#[doc(hidden)]
+#[track_caller]
+pub fn __color_value<T: TryInto<u8> + Copy + std::fmt::Display>(value: T) -> u8 {
+    value.try_into().unwrap_or_else(|_| panic!("color value {} is out of range, expected 0 to 255", value))
+}
+
+/// Parses a `#rrggbb` color in `const` context for `style!` and `paint!`, turning invalid colors
+/// into compile errors.
+#[doc(hidden)]
+pub const fn __const_hex(hex: &str) -> (u8, u8, u8) {
+    let bytes = hex.as_bytes();
+    let start = if !bytes.is_empty() && bytes[0] == b'#' { 1 } else { 0 };
+    if bytes.len() - start != 6 {
+        panic!("hex colors must have the form \"#rrggbb\"");
+    }
+    let mut rgb = [0u8; 3];
+    let mut i = 0;
+    while i < 6 {
+        let digit = match bytes[start + i] {
+            b @ b'0'..=b'9' => b - b'0',
+            b @ b'a'..=b'f' => b - b'a' + 10,
+            b @ b'A'..=b'F' => b - b'A' + 10,
+            _ => panic!("hex colors may only contain the digits 0-9 and a-f"),
+        };
+        rgb[i / 2] = rgb[i / 2] * 16 + digit;
+        i += 1;
+    }
+    (rgb[0], rgb[1], rgb[2])
+}
+
 /// Converts a hexadecimal color string to its RGB components.
 ///
 /// Accepts a hexadecimal string in the format of "RRGGBB" or "#RRGGBB" and converts
@@ -160,17 +190,17 @@ impl std::fmt::Display for HexError {
 /// ```
 pub fn to_rgb<D: std::fmt::Display>(value: D) -> Result<(u8, u8, u8), HexError> {
     let v = &value.to_string();
-    let hex = if v.starts_with('#') { &v[1..] } else { v };
+    let hex = v.strip_prefix('#').unwrap_or(v);
 
     match hex.len() {
         6 => {
             let r = u8::from_str_radix(&hex[0..2], 16)?;
             let g = u8::from_str_radix(&hex[2..4], 16)?;
             let b = u8::from_str_radix(&hex[4..6], 16)?;
-            return Ok((r, g, b));
+            Ok((r, g, b))
         },
-        _ => return Err(HexError::InvalidLength),
-    };
+        _ => Err(HexError::InvalidLength),
+    }
 }
 
 /// Applies ANSI styling to a string using the `Style` enum.
@@ -180,7 +210,16 @@ pub fn to_rgb<D: std::fmt::Display>(value: D) -> Result<(u8, u8, u8), HexError>
 ///
 /// # Usage
 ///
-/// Pass one or more `Style` enum variants, followed by `=>` and the string to format.
+/// Pass one or more styles separated by commas, followed by `=>` and the value to format. The
+/// styles can be given in any order and number, each being one of:
+///
+/// - A `Style` variant name, e.g. `Bold` or `FGBlue`
+/// - `(FGRGB, r, g, b)` or `(BGRGB, r, g, b)` for an RGB color from three `u8` values
+/// - `(FGRGB, "#rrggbb")` or `(BGRGB, "#rrggbb")` for an RGB color from a hex string, checked at
+///   compile time
+/// - `(FG256, n)` or `(BG256, n)` for an entry of the 256 color palette
+///
+/// The escape codes are written in the order the styles are given.
 ///
 /// # Examples
 ///
@@ -189,39 +228,241 @@ pub fn to_rgb<D: std::fmt::Display>(value: D) -> Result<(u8, u8, u8), HexError>
 ///
 /// let styled_text = style!(Bold, FGBlue, BGWhite => "Styled Text");
 /// println!("{}", styled_text);
+///
+/// let mixed = style!(Bold, Italic, (FGRGB, 1, 2, 3), (BG256, 236), Underline, (BGRGB, "#ff8800") => "x");
+/// assert_eq!(mixed, "\x1b[1;3;38;2;1;2;3;48;5;236;4;48;2;255;136;0mx\x1b[0m");
 /// ```
 ///
 /// This example applies bold blue text on a white background to "Styled Text".
 ///
+/// # Errors
+///
+/// Misuse is reported at compile time with the accepted forms, e.g. for an unknown name:
+///
+/// ```compile_fail
+/// # use dekor::*;
+/// // error: unknown style `FGOrange`, expected one of: Reset, Bold, ...
+/// let text = style!(Bold, FGOrange => "text");
+/// ```
+///
+/// Literal RGB components and palette indices must fit in a `u8`, and hex strings must be valid;
+/// components given as other integer expressions are converted at runtime and panic when out of
+/// range:
+///
+/// ```compile_fail
+/// # use dekor::*;
+/// let text = style!((FGRGB, 300, 0, 0) => "text");
+/// ```
+///
+/// ```compile_fail
+/// # use dekor::*;
+/// // error: hex colors must have the form "#rrggbb"
+/// let text = style!((BGRGB, "#fff") => "text");
+/// ```
+///
+/// ```compile_fail
+/// # use dekor::*;
+/// // error: hex colors may only contain the digits 0-9 and a-f
+/// let text = paint!((FGRGB, "#ggffff") => "text");
+/// ```
+///
+/// ```compile_fail
+/// # use dekor::*;
+/// // error: `style!` is missing `=> input` after the styles
+/// let text = style!(Bold, Italic);
+/// ```
+///
 /// # Notes
 ///
 /// - The macro appends an ANSI reset sequence at the end of the formatted string to ensure that the styling does not affect subsequent text.
 ///
 #[macro_export]
 macro_rules! style {
-    ($($v:ident),+ => $input:expr$(,)?) => {{
-        let mut codes = Vec::new();
-        $(
-            codes.push(
-                format!("{}", $crate::style::Style::$v),
-            );
-        )+
-        format!("\x1b[{}m{}\x1b[0m", codes.join(";"), $input)
+    ($($item:tt),+ => $input:expr$(,)?) => {
+        format!("\x1b[{}m{}\x1b[0m", [$($crate::__style_item!(@code $item)),+].join(";"), $input)
+    };
+    (=> $($rest:tt)*) => {
+        compile_error!("`style!` needs at least one style before `=>`, e.g. `style!(Bold, FGRed => text)`")
+    };
+    ($($item:tt),+) => {
+        compile_error!("`style!` is missing `=> input` after the styles, e.g. `style!(Bold, FGRed => text)`")
+    };
+    ($($rest:tt)*) => {
+        compile_error!("expected `style!(Style, ... => input)`, with the styles separated by commas")
+    };
+}
+
+/// Expands a single style of `style!` or `paint!`, into its SGR parameters (`@code`) or a `Paint`
+/// (`@paint`), reporting misuse with `compile_error!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __style_item {
+    (@style Reset) => { $crate::style::Style::Reset };
+    (@style Bold) => { $crate::style::Style::Bold };
+    (@style Italic) => { $crate::style::Style::Italic };
+    (@style Underline) => { $crate::style::Style::Underline };
+    (@style FGBlack) => { $crate::style::Style::FGBlack };
+    (@style FGRed) => { $crate::style::Style::FGRed };
+    (@style FGGreen) => { $crate::style::Style::FGGreen };
+    (@style FGYellow) => { $crate::style::Style::FGYellow };
+    (@style FGBlue) => { $crate::style::Style::FGBlue };
+    (@style FGPurple) => { $crate::style::Style::FGPurple };
+    (@style FGCyan) => { $crate::style::Style::FGCyan };
+    (@style FGWhite) => { $crate::style::Style::FGWhite };
+    (@style BGBlack) => { $crate::style::Style::BGBlack };
+    (@style BGRed) => { $crate::style::Style::BGRed };
+    (@style BGGreen) => { $crate::style::Style::BGGreen };
+    (@style BGYellow) => { $crate::style::Style::BGYellow };
+    (@style BGBlue) => { $crate::style::Style::BGBlue };
+    (@style BGPurple) => { $crate::style::Style::BGPurple };
+    (@style BGCyan) => { $crate::style::Style::BGCyan };
+    (@style BGWhite) => { $crate::style::Style::BGWhite };
+    (@style FGRGB) => { compile_error!("`FGRGB` needs a color: `(FGRGB, r, g, b)` or `(FGRGB, \"#rrggbb\")`") };
+    (@style BGRGB) => { compile_error!("`BGRGB` needs a color: `(BGRGB, r, g, b)` or `(BGRGB, \"#rrggbb\")`") };
+    (@style FG256) => { compile_error!("`FG256` needs a palette index: `(FG256, n)`") };
+    (@style BG256) => { compile_error!("`BG256` needs a palette index: `(BG256, n)`") };
+    (@style $other:ident) => {
+        compile_error!(concat!("unknown style `", stringify!($other), "`, expected one of: Reset, Bold, Italic, Underline, FGBlack, FGRed, FGGreen, FGYellow, FGBlue, FGPurple, FGCyan, FGWhite, BGBlack, BGRed, BGGreen, BGYellow, BGBlue, BGPurple, BGCyan, BGWhite, or a color: (FGRGB, r, g, b), (FGRGB, \"#rrggbb\"), (FG256, n) or their BG forms"))
+    };
+
+    (@color (FGRGB, $r:literal, $g:literal, $b:literal)) => { { let (r, g, b): (u8, u8, u8) = ($r, $g, $b); (false, $crate::style::Color::Rgb(r, g, b)) } };
+    (@color (BGRGB, $r:literal, $g:literal, $b:literal)) => { { let (r, g, b): (u8, u8, u8) = ($r, $g, $b); (true, $crate::style::Color::Rgb(r, g, b)) } };
+    (@color (FGRGB, $r:expr, $g:expr, $b:expr)) => { { use $crate::style::__color_value as v; (false, $crate::style::Color::Rgb(v($r), v($g), v($b))) } };
+    (@color (BGRGB, $r:expr, $g:expr, $b:expr)) => { { use $crate::style::__color_value as v; (true, $crate::style::Color::Rgb(v($r), v($g), v($b))) } };
+    (@color (FGRGB, $hex:expr)) => { { const RGB: (u8, u8, u8) = $crate::style::__const_hex($hex); (false, $crate::style::Color::Rgb(RGB.0, RGB.1, RGB.2)) } };
+    (@color (BGRGB, $hex:expr)) => { { const RGB: (u8, u8, u8) = $crate::style::__const_hex($hex); (true, $crate::style::Color::Rgb(RGB.0, RGB.1, RGB.2)) } };
+    (@color (FG256, $n:literal)) => { { let n: u8 = $n; (false, $crate::style::Color::Fixed(n)) } };
+    (@color (BG256, $n:literal)) => { { let n: u8 = $n; (true, $crate::style::Color::Fixed(n)) } };
+    (@color (FG256, $n:expr)) => { (false, $crate::style::Color::Fixed($crate::style::__color_value($n))) };
+    (@color (BG256, $n:expr)) => { (true, $crate::style::Color::Fixed($crate::style::__color_value($n))) };
+    (@color (FGRGB $($rest:tt)*)) => { compile_error!("expected `(FGRGB, r, g, b)` or `(FGRGB, \"#rrggbb\")`") };
+    (@color (BGRGB $($rest:tt)*)) => { compile_error!("expected `(BGRGB, r, g, b)` or `(BGRGB, \"#rrggbb\")`") };
+    (@color (FG256 $($rest:tt)*)) => { compile_error!("expected `(FG256, n)` with a palette index from 0 to 255") };
+    (@color (BG256 $($rest:tt)*)) => { compile_error!("expected `(BG256, n)` with a palette index from 0 to 255") };
+    (@color ($($other:tt)*)) => {
+        compile_error!(concat!("unknown color `(", stringify!($($other)*), ")`, expected one of: (FGRGB, r, g, b), (FGRGB, \"#rrggbb\"), (FG256, n) or their BG forms"))
+    };
+
+    (@code $v:ident) => { $crate::__style_item!(@style $v).to_string() };
+    (@code ($($color:tt)*)) => { $crate::style::Paint::__color_code($crate::__style_item!(@color ($($color)*))) };
+    (@paint $v:ident) => { $crate::style::Paint::new().with($crate::__style_item!(@style $v)) };
+    (@paint ($($color:tt)*)) => { $crate::style::Paint::__from_color($crate::__style_item!(@color ($($color)*))) };
+    (@$mode:ident $other:tt) => {
+        compile_error!(concat!("expected a style name or a color, found `", stringify!($other), "`"))
+    };
+}
+
+/// Lazily applies styles to a value, with the same syntax as `style!` but returning a `Painted`
+/// instead of a `String`.
+///
+/// Since a `Paint` holds a single foreground and background color, the last color of each wins and
+/// the escape codes are written in a fixed order.
+///
+/// The value is borrowed, so it must outlive the result; formatting flags such as width and
+/// precision apply to the value inside the escape codes.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let name = "dekor";
+/// let version = 0.2;
+/// let banner = format!("{} {:.1}", paint!(Bold, FGBlue => name), paint!((FGRGB, 0, 200, 0) => version));
+/// assert_eq!(banner, "\x1b[1;34mdekor\x1b[0m \x1b[38;2;0;200;0m0.2\x1b[0m");
+/// ```
+///
+#[macro_export]
+macro_rules! paint {
+    ($($item:tt),+ => $input:expr$(,)?) => {
+        $crate::style::Paint::new()$(.merge($crate::__style_item!(@paint $item)))+.paint(&$input)
+    };
+    ($($rest:tt)*) => {
+        compile_error!("expected `paint!(Style, ... => value)`, with the styles separated by commas")
+    };
+}
+
+/// Styles a string literal at compile time, producing a `&'static str`.
+///
+/// Takes the same styles as `style!`, except hex colors, but both the styles and the input must
+/// be literals: the escape codes are joined with `concat!`, so the result can initialize a `const`
+/// or `static`.
+/// For styles built at compile time but applied at runtime, see the `const fn`s of `Paint`.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// const ERROR: &str = style_const!(Bold, FGRed => "error:");
+/// static BANNER: &str = style_const!((FGRGB, 255, 140, 0), Underline => "dekor");
+/// static HIGHLIGHT: Paint = Paint::new().bold().bg(Color::Fixed(236));
+///
+/// assert_eq!(ERROR, "\x1b[1;31merror:\x1b[0m");
+/// assert_eq!(ERROR, style!(Bold, FGRed => "error:"));
+/// assert_eq!(BANNER, "\x1b[38;2;255;140;0;4mdekor\x1b[0m");
+/// assert_eq!(HIGHLIGHT.paint("x").to_string(), "\x1b[1;48;5;236mx\x1b[0m");
+/// ```
+///
+#[macro_export]
+macro_rules! style_const {
+    ($first:tt $(, $rest:tt)* => $input:literal$(,)?) => {{
+        $crate::__style_code!(@check $first);
+        $($crate::__style_code!(@check $rest);)*
+        concat!("\x1b[", $crate::__style_code!($first), $(";", $crate::__style_code!($rest),)* "m", $input, "\x1b[0m")
     }};
+    ($($item:tt),+ => $input:expr$(,)?) => {
+        compile_error!("`style_const!` only styles literals, use `style!` or `paint!` for other values")
+    };
+    ($($rest:tt)*) => {
+        compile_error!("expected `style_const!(Style, ... => \"literal\")`, with the styles separated by commas")
+    };
+}
+
+/// Maps the styles of `style_const!` to their SGR parameters as literals, and checks the range of
+/// color values.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __style_code {
+    (@check ($kind:ident, $r:literal, $g:literal, $b:literal)) => { const _: [u8; 3] = [$r, $g, $b]; };
+    // Hex colors are reported by the expansion itself
+    (@check (FGRGB, $hex:literal)) => {};
+    (@check (BGRGB, $hex:literal)) => {};
+    (@check ($kind:ident, $n:literal)) => { const _: u8 = $n; };
+    (@check $other:tt) => {};
 
-    ($($v1:ident,)? $(($rgb:ident, $r:expr, $g:expr, $b:expr)),+ $(,$v2:ident)? => $input:expr$(,)?) => {{
-        let mut codes = Vec::new();
-        $(codes.push(
-            format!("{}", $crate::style::Style::$v1)
-        );)?
-        $(codes.push(
-            format!("{};2;{};{};{}", $crate::style::Style::$rgb, $r, $g, $b),
-        );)+
-        $(codes.push(
-            format!("{}", $crate::style::Style::$v2)
-        );)?
-        format!("\x1b[{}m{}\x1b[0m", codes.join(";"), $input)
-    }}
+    (Reset) => { "0" };
+    (Bold) => { "1" };
+    (Italic) => { "3" };
+    (Underline) => { "4" };
+    (FGBlack) => { "30" };
+    (FGRed) => { "31" };
+    (FGGreen) => { "32" };
+    (FGYellow) => { "33" };
+    (FGBlue) => { "34" };
+    (FGPurple) => { "35" };
+    (FGCyan) => { "36" };
+    (FGWhite) => { "37" };
+    (BGBlack) => { "40" };
+    (BGRed) => { "41" };
+    (BGGreen) => { "42" };
+    (BGYellow) => { "43" };
+    (BGBlue) => { "44" };
+    (BGPurple) => { "45" };
+    (BGCyan) => { "46" };
+    (BGWhite) => { "47" };
+    ((FGRGB, $r:literal, $g:literal, $b:literal)) => { concat!("38;2;", $r, ";", $g, ";", $b) };
+    ((BGRGB, $r:literal, $g:literal, $b:literal)) => { concat!("48;2;", $r, ";", $g, ";", $b) };
+    ((FGRGB, $hex:literal)) => { compile_error!("`style_const!` cannot convert hex colors, use `(FGRGB, r, g, b)`") };
+    ((BGRGB, $hex:literal)) => { compile_error!("`style_const!` cannot convert hex colors, use `(BGRGB, r, g, b)`") };
+    ((FG256, $n:literal)) => { concat!("38;5;", $n) };
+    ((BG256, $n:literal)) => { concat!("48;5;", $n) };
+    (($($color:tt)*)) => {
+        compile_error!(concat!("expected `(FGRGB, r, g, b)`, `(FG256, n)` or their BG forms with literal values, found `(", stringify!($($color)*), ")`"))
+    };
+    ($other:ident) => {
+        compile_error!(concat!("unknown style `", stringify!($other), "`, expected one of: Reset, Bold, Italic, Underline, FGBlack, FGRed, FGGreen, FGYellow, FGBlue, FGPurple, FGCyan, FGWhite, BGBlack, BGRed, BGGreen, BGYellow, BGBlue, BGPurple, BGCyan, BGWhite"))
+    };
 }
 
 /// Applies ANSI styling to a string based on a list of `Style` variants and a displayable input.
@@ -265,7 +506,7 @@ where I: IntoIterator<Item = Style>, D: std::fmt::Display
 {
     let styles: Vec<String> = styles.into_iter().map(|s| s.to_string()).collect();
     
-    return format!("\x1b[{}m{}\x1b[0m", styles.join(";"), input);
+    format!("\x1b[{}m{}\x1b[0m", styles.join(";"), input)
 }
 
 /// Applies ANSI styling to a string based on a mix of `Style` variants and RGB color specifications.
@@ -319,7 +560,384 @@ where I: IntoIterator<Item = (Style, u8, u8, u8)>, D: std::fmt::Display
             _ => s.0.to_string(),
         }).collect();
     
-    return format!("\x1b[{}m{}\x1b[0m", styles.join(";"), input);
+    format!("\x1b[{}m{}\x1b[0m", styles.join(";"), input)
+}
+
+/// A foreground or background color: one of the eight standard colors, an entry of the 256 color
+/// palette or a 24-bit RGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Purple,
+    Cyan,
+    White,
+    /// An entry of the 256 color palette
+    Fixed(u8),
+    /// A 24-bit color
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Parses a `#RRGGBB` or `RRGGBB` string into an RGB color, see `to_rgb`.
+    pub fn hex<D: std::fmt::Display>(value: D) -> Result<Color, HexError> {
+        let (r, g, b) = to_rgb(value)?;
+        Ok(Color::Rgb(r, g, b))
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Color::Rgb(r, g, b)
+    }
+}
+
+/// A complete text style, i.e. the attributes and colors that a run of text is drawn with.
+///
+/// Unlike a list of `Style`s, a `Paint` is a small `Copy` value that needs no allocation, can be
+/// compared and can be built in `const` context. Its `Display` implementation writes the escape
+/// sequence that turns the style on (and nothing for a plain `Paint`); `Paint::RESET` turns it
+/// off again.
+///
+/// A `Paint` converts from a single `Style` and collects from `Style`s and from the
+/// `(Style, r, g, b)` tuples accepted by `styler()`.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// const WARNING: Paint = Paint::new().bold().fg(Color::Yellow);
+/// assert_eq!(format!("{}warning{}", WARNING, Paint::RESET), "\x1b[1;33mwarning\x1b[0m");
+///
+/// let paint: Paint = [Style::Underline, Style::BGBlue].into_iter().collect();
+/// assert_eq!(paint, Paint::new().underline().bg(Color::Blue));
+///
+/// let rgb: Paint = [(Style::FGRGB, 255, 100, 0)].into_iter().collect();
+/// assert_eq!(rgb.to_string(), "\x1b[38;2;255;100;0m");
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Paint {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    bold: bool,
+    italic: bool,
+    underline: bool,
+}
+
+impl Paint {
+    /// The escape sequence that resets every style.
+    pub const RESET: &'static str = "\x1b[0m";
+
+    /// Creates a plain style, without colors or attributes.
+    pub const fn new() -> Self {
+        Paint { fg: None, bg: None, bold: false, italic: false, underline: false }
+    }
+
+    /// Sets the foreground color.
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Sets the background color.
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Makes the text bold.
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Makes the text italic.
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Underlines the text.
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Adds a `Style`. `Style::Reset` clears the paint, and `Style::FGRGB` and `Style::BGRGB`,
+    /// which need color values, are ignored.
+    pub const fn with(self, style: Style) -> Self {
+        match style {
+            Style::Reset => Paint::new(),
+            Style::Bold => self.bold(),
+            Style::Italic => self.italic(),
+            Style::Underline => self.underline(),
+            Style::FGBlack => self.fg(Color::Black),
+            Style::FGRed => self.fg(Color::Red),
+            Style::FGGreen => self.fg(Color::Green),
+            Style::FGYellow => self.fg(Color::Yellow),
+            Style::FGBlue => self.fg(Color::Blue),
+            Style::FGPurple => self.fg(Color::Purple),
+            Style::FGCyan => self.fg(Color::Cyan),
+            Style::FGWhite => self.fg(Color::White),
+            Style::BGBlack => self.bg(Color::Black),
+            Style::BGRed => self.bg(Color::Red),
+            Style::BGGreen => self.bg(Color::Green),
+            Style::BGYellow => self.bg(Color::Yellow),
+            Style::BGBlue => self.bg(Color::Blue),
+            Style::BGPurple => self.bg(Color::Purple),
+            Style::BGCyan => self.bg(Color::Cyan),
+            Style::BGWhite => self.bg(Color::White),
+            Style::FGRGB | Style::BGRGB => self,
+        }
+    }
+
+    /// Layers `other` on top of this paint: its colors replace these and its attributes are added.
+    pub const fn merge(self, other: Paint) -> Self {
+        Paint {
+            fg: if other.fg.is_some() { other.fg } else { self.fg },
+            bg: if other.bg.is_some() { other.bg } else { self.bg },
+            bold: self.bold || other.bold,
+            italic: self.italic || other.italic,
+            underline: self.underline || other.underline,
+        }
+    }
+
+    /// Creates the paint of a `(background, color)` pair expanded by `paint!`.
+    #[doc(hidden)]
+    pub const fn __from_color((background, color): (bool, Color)) -> Self {
+        if background { Paint::new().bg(color) } else { Paint::new().fg(color) }
+    }
+
+    /// Returns the SGR parameters of a `(background, color)` pair expanded by `style!`.
+    #[doc(hidden)]
+    pub fn __color_code(color: (bool, Color)) -> String {
+        let mut code = String::new();
+        // Writing to a `String` cannot fail
+        let _ = Paint::write_transition(&Paint::new(), &Paint::__from_color(color), &mut code);
+        code.trim_start_matches("\x1b[").trim_end_matches('m').to_string()
+    }
+
+    /// Returns `true` if the paint has no colors and no attributes.
+    pub const fn is_plain(&self) -> bool {
+        self.fg.is_none() && self.bg.is_none() && !self.bold && !self.italic && !self.underline
+    }
+
+    /// Writes the shortest escape sequence that changes the style of the terminal from `from` to
+    /// `to`, or nothing when they are equal.
+    pub(crate) fn write_transition<F: std::fmt::Write>(from: &Paint, to: &Paint, out: &mut F) -> std::fmt::Result {
+        let mut params = Params { out, empty: true };
+        if to.is_plain() {
+            if !from.is_plain() {
+                params.push(format_args!("0"))?;
+            }
+            return params.finish();
+        }
+        let mut toggle = |from: bool, to: bool, on: u8, off: u8| match (from, to) {
+            (false, true) => params.push(format_args!("{}", on)),
+            (true, false) => params.push(format_args!("{}", off)),
+            _ => Ok(()),
+        };
+        toggle(from.bold, to.bold, 1, 22)?;
+        toggle(from.italic, to.italic, 3, 23)?;
+        toggle(from.underline, to.underline, 4, 24)?;
+        for (from, to, base) in [(from.fg, to.fg, 30), (from.bg, to.bg, 40)] {
+            if from != to {
+                params.color(to, base)?;
+            }
+        }
+        params.finish()
+    }
+}
+
+impl std::fmt::Display for Paint {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        Paint::write_transition(&Paint::new(), self, f)
+    }
+}
+
+impl From<Style> for Paint {
+    fn from(style: Style) -> Self {
+        Paint::new().with(style)
+    }
+}
+
+impl FromIterator<Style> for Paint {
+    fn from_iter<I: IntoIterator<Item = Style>>(styles: I) -> Self {
+        styles.into_iter().fold(Paint::new(), Paint::with)
+    }
+}
+
+impl FromIterator<(Style, u8, u8, u8)> for Paint {
+    fn from_iter<I: IntoIterator<Item = (Style, u8, u8, u8)>>(styles: I) -> Self {
+        styles.into_iter().fold(Paint::new(), |paint, (style, r, g, b)| match style {
+            Style::FGRGB => paint.fg(Color::Rgb(r, g, b)),
+            Style::BGRGB => paint.bg(Color::Rgb(r, g, b)),
+            style => paint.with(style),
+        })
+    }
+}
+
+/// A value displayed in a `Paint`, created with `paint()`, `painter()`, `Paint::paint` or the
+/// `paint!` macro.
+///
+/// Nothing is formatted or allocated up front: `Display` writes the style's escape sequence,
+/// formats the value with the same `Formatter`, so width, alignment and precision flags apply to
+/// the value itself, and writes the reset sequence. A plain paint writes the value alone.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let total = 3.14159;
+/// let line = format!("[{:>6.2}]", paint([Style::Bold], &total));
+/// assert_eq!(line, "[\x1b[1m  3.14\x1b[0m]");
+///
+/// let plain = Paint::new().paint("as is");
+/// assert_eq!(format!("{:-<7}", plain), "as is--");
+/// ```
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Painted<'a, T: std::fmt::Display + ?Sized> {
+    paint: Paint,
+    value: &'a T,
+}
+
+impl<'a, T: std::fmt::Display + ?Sized> Painted<'a, T> {
+    /// Returns the paint the value is displayed in.
+    pub fn paint(&self) -> Paint {
+        self.paint
+    }
+
+    /// Returns the wrapped value.
+    pub fn value(&self) -> &'a T {
+        self.value
+    }
+}
+
+impl<T: std::fmt::Display + ?Sized> std::fmt::Display for Painted<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.paint.is_plain() {
+            return self.value.fmt(f);
+        }
+        Paint::write_transition(&Paint::new(), &self.paint, f)?;
+        self.value.fmt(f)?;
+        f.write_str(Paint::RESET)
+    }
+}
+
+impl Paint {
+    /// Wraps `value` to be displayed in this paint, see `Painted`.
+    pub const fn paint<T: std::fmt::Display + ?Sized>(self, value: &T) -> Painted<'_, T> {
+        Painted { paint: self, value }
+    }
+}
+
+/// Lazily applies `Style`s to a value, like `style()` but without allocating a `String`.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+/// use std::fmt::Write;
+///
+/// let mut log = String::new();
+/// write!(log, "{} {:>4}ms", paint([Style::FGGreen], "done"), paint([Style::Bold], &12)).unwrap();
+/// assert_eq!(log, "\x1b[32mdone\x1b[0m \x1b[1m  12\x1b[0mms");
+/// ```
+///
+pub fn paint<I, T>(styles: I, value: &T) -> Painted<'_, T>
+where I: IntoIterator<Item = Style>, T: std::fmt::Display + ?Sized
+{
+    styles.into_iter().collect::<Paint>().paint(value)
+}
+
+/// Lazily applies `Style`s and RGB colors to a value, like `styler()` but without allocating a
+/// `String`.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let orange = painter([(Style::FGRGB, 255, 140, 0), (Style::Italic, 0, 0, 0)], "orange");
+/// assert_eq!(orange.to_string(), "\x1b[3;38;2;255;140;0morange\x1b[0m");
+/// ```
+///
+pub fn painter<I, T>(styles: I, value: &T) -> Painted<'_, T>
+where I: IntoIterator<Item = (Style, u8, u8, u8)>, T: std::fmt::Display + ?Sized
+{
+    styles.into_iter().collect::<Paint>().paint(value)
+}
+
+/// A value with a style of its own, typically a status enum whose variants each have a color.
+///
+/// Usually derived with `#[derive(Styled)]`, available with the `derive` feature, which also
+/// implements a styled `Display`; the paint can then be reused, e.g. with a `StyledWriter`.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// enum Level { Info, Error }
+///
+/// impl Styled for Level {
+///     fn paint(&self) -> Paint {
+///         match self {
+///             Level::Info => Paint::new().fg(Color::Blue),
+///             Level::Error => Paint::new().bold().fg(Color::Red),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Level::Error.paint().paint("E").to_string(), "\x1b[1;31mE\x1b[0m");
+/// ```
+///
+pub trait Styled {
+    /// Returns the style the value is displayed in.
+    fn paint(&self) -> Paint;
+}
+
+/// Writes SGR parameters as a single `ESC [ ... m` sequence, omitted when no parameter is pushed.
+struct Params<'a, F: std::fmt::Write> {
+    out: &'a mut F,
+    empty: bool,
+}
+
+impl<F: std::fmt::Write> Params<'_, F> {
+    fn push(&mut self, param: std::fmt::Arguments) -> std::fmt::Result {
+        self.out.write_str(if self.empty { "\x1b[" } else { ";" })?;
+        self.empty = false;
+        self.out.write_fmt(param)
+    }
+
+    /// Pushes a foreground (`base` 30) or background (`base` 40) color, or its default.
+    fn color(&mut self, color: Option<Color>, base: u8) -> std::fmt::Result {
+        let standard = match color {
+            None => 9,
+            Some(Color::Fixed(n)) => return self.push(format_args!("{};5;{}", base + 8, n)),
+            Some(Color::Rgb(r, g, b)) => return self.push(format_args!("{};2;{};{};{}", base + 8, r, g, b)),
+            Some(Color::Black) => 0,
+            Some(Color::Red) => 1,
+            Some(Color::Green) => 2,
+            Some(Color::Yellow) => 3,
+            Some(Color::Blue) => 4,
+            Some(Color::Purple) => 5,
+            Some(Color::Cyan) => 6,
+            Some(Color::White) => 7,
+        };
+        self.push(format_args!("{}", base + standard))
+    }
+
+    fn finish(self) -> std::fmt::Result {
+        if self.empty { Ok(()) } else { self.out.write_str("m") }
+    }
 }
 
 // ################################################# Tests #################################################
@@ -345,6 +963,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::useless_vec)]
     fn test_style_output() {
         let styles = vec![Style::Bold, Style::FGRed];
         let input = "Hello, world!";
@@ -364,4 +983,49 @@ mod tests {
         assert_eq!(styler(styles, input), expected);
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_paint_transitions() {
+        let mut out = String::new();
+        let red = Paint::new().fg(Color::Red);
+        let bold_red = red.bold();
+        Paint::write_transition(&Paint::new(), &bold_red, &mut out).unwrap();
+        Paint::write_transition(&bold_red, &red.bg(Color::Fixed(236)), &mut out).unwrap();
+        Paint::write_transition(&red, &red, &mut out).unwrap();
+        Paint::write_transition(&red, &Paint::new().italic(), &mut out).unwrap();
+        Paint::write_transition(&red, &Paint::new(), &mut out).unwrap();
+        assert_eq!(out, "\x1b[1;31m\x1b[22;48;5;236m\x1b[3;39m\x1b[0m");
+    }
+
+    #[test]
+    fn test_painted_delegates_flags() {
+        let red = Paint::new().fg(Color::Red);
+        assert_eq!(format!("{:^7}|{:.3}", red.paint("ab"), red.paint(&1.0f64)), "\x1b[31m  ab   \x1b[0m|\x1b[31m1.000\x1b[0m");
+        assert_eq!(paint!(Bold => "x").to_string(), style!(Bold => "x"));
+    }
+
+    #[test]
+    fn test_style_const_matches_style() {
+        const STYLED: &str = style_const!(Italic, BGCyan, (BGRGB, 1, 2, 3) => "x");
+        assert_eq!(STYLED, "\x1b[3;46;48;2;1;2;3mx\x1b[0m");
+        assert_eq!(style_const!(Reset => 1), style!(Reset => 1));
+    }
+
+    #[test]
+    fn test_style_any_order() {
+        let (r, g) = (10, 20);
+        assert_eq!(style!((FG256, 208), Bold, (BGRGB, r, g, 30), Italic => 1), "\x1b[38;5;208;1;48;2;10;20;30;3m1\x1b[0m");
+        let (r, g, b, n): (i32, usize, u64, i64) = (1, 2, 3, 4);
+        assert_eq!(style!((FGRGB, r, g, b), (BG256, n) => ""), "\x1b[38;2;1;2;3;48;5;4m\x1b[0m");
+        assert_eq!(style!((FGRGB, "00ff7f") => ""), "\x1b[38;2;0;255;127m\x1b[0m");
+        let painted = paint!((FGRGB, 1, 2, 3), Underline, (FG256, 9), (BGRGB, "#000000") => "x");
+        assert_eq!(painted.paint(), Paint::new().underline().fg(Color::Fixed(9)).bg(Color::Rgb(0, 0, 0)));
+        assert_eq!(style_const!((BG256, 17), Bold => "y"), "\x1b[48;5;17;1my\x1b[0m");
+    }
+
+    #[test]
+    #[should_panic(expected = "color value 256 is out of range")]
+    fn test_style_value_out_of_range() {
+        let n = 256;
+        let _ = style!((FG256, n) => "");
+    }
+}
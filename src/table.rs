@@ -0,0 +1,391 @@
+use crate::border::Border;
+use crate::characters::Utf8;
+use crate::style::{style, Style};
+use crate::text::{self, Align};
+
+/// What to do with cell content wider than its column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Overflow {
+    /// Wrap the content onto additional lines
+    #[default]
+    Wrap,
+    /// Cut the content and end it with an ellipsis (`…`)
+    Truncate,
+}
+
+/// Layout settings for a single column of a `Table`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Column {
+    pub align: Align,
+    pub min_width: usize,
+    pub max_width: Option<usize>,
+    pub overflow: Overflow,
+}
+
+/// A single table cell: its content, which may already contain escape codes, and optional styles
+/// that take precedence over the table's header or row styles.
+#[derive(Debug, Clone, Default)]
+pub struct Cell {
+    content: String,
+    styles: Vec<Style>,
+    align: Option<Align>,
+}
+
+impl Cell {
+    /// Creates an unstyled cell.
+    pub fn new<D: std::fmt::Display>(content: D) -> Self {
+        Cell { content: content.to_string(), styles: Vec::new(), align: None }
+    }
+
+    /// Styles the cell, replacing the header or row styles of the table for this cell.
+    pub fn style<I: IntoIterator<Item = Style>>(mut self, styles: I) -> Self {
+        self.styles = styles.into_iter().collect();
+        self
+    }
+
+    /// Aligns the cell, overriding the alignment of its column.
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /// Returns the content as written with `border`, escaping the pipes of Markdown tables and
+    /// joining their lines with `<br>`, since a Markdown row cannot span several lines.
+    fn text(&self, border: Border) -> std::borrow::Cow<'_, str> {
+        if border == Border::Markdown && self.content.contains(['|', '\n']) {
+            self.content.replace('|', "\\|").lines().collect::<Vec<_>>().join("<br>").into()
+        } else {
+            self.content.as_str().into()
+        }
+    }
+}
+
+impl<D: std::fmt::Display> From<D> for Cell {
+    fn from(content: D) -> Self {
+        Cell::new(content)
+    }
+}
+
+/// Renders rows of cells as a table with box-drawing borders.
+///
+/// Widths are measured by display width, so cells containing escape codes from `style()` or wide
+/// characters line up correctly. Each column can be aligned, constrained to a minimum and maximum
+/// width, and either wrap or truncate content that does not fit.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let table = Table::new()
+///     .headers(["Name", "Size"])
+///     .row(["Cargo.toml", "412"])
+///     .row(["README.md", "3120"])
+///     .align(1, Align::Right);
+///
+/// assert_eq!(table.to_string(), "\
+/// ┌────────────┬──────┐
+/// │ Name       │ Size │
+/// ├────────────┼──────┤
+/// │ Cargo.toml │  412 │
+/// │ README.md  │ 3120 │
+/// └────────────┴──────┘
+/// ");
+///
+/// let markdown = Table::new()
+///     .headers(["Name", "Size"])
+///     .row(["Cargo.toml", "412"])
+///     .align(1, Align::Right)
+///     .border(Border::Markdown);
+///
+/// assert_eq!(markdown.to_string(), "\
+/// | Name       | Size |
+/// | ---------- | ---: |
+/// | Cargo.toml |  412 |
+/// ");
+/// ```
+///
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    headers: Vec<Cell>,
+    rows: Vec<Vec<Cell>>,
+    columns: Vec<Column>,
+    border: Border,
+    header_style: Vec<Style>,
+    row_style: Vec<Style>,
+}
+
+impl Table {
+    /// Creates an empty table with `Border::Slim` borders.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the header cells.
+    pub fn headers<I>(mut self, headers: I) -> Self
+    where I: IntoIterator, I::Item: Into<Cell>
+    {
+        self.headers = headers.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Appends a row of cells.
+    pub fn row<I>(mut self, cells: I) -> Self
+    where I: IntoIterator, I::Item: Into<Cell>
+    {
+        self.push_row(cells);
+        self
+    }
+
+    /// Appends a row of cells to an existing table.
+    pub fn push_row<I>(&mut self, cells: I)
+    where I: IntoIterator, I::Item: Into<Cell>
+    {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+    }
+
+    /// Sets the border preset.
+    pub fn border(mut self, border: Border) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the styles applied to every header cell without styles of its own.
+    pub fn header_style<I: IntoIterator<Item = Style>>(mut self, styles: I) -> Self {
+        self.header_style = styles.into_iter().collect();
+        self
+    }
+
+    /// Sets the styles applied to every body cell without styles of its own.
+    pub fn row_style<I: IntoIterator<Item = Style>>(mut self, styles: I) -> Self {
+        self.row_style = styles.into_iter().collect();
+        self
+    }
+
+    /// Replaces the layout settings of the column at `index`.
+    pub fn column(mut self, index: usize, column: Column) -> Self {
+        *self.column_mut(index) = column;
+        self
+    }
+
+    /// Sets the alignment of the column at `index`.
+    pub fn align(mut self, index: usize, align: Align) -> Self {
+        self.column_mut(index).align = align;
+        self
+    }
+
+    /// Sets the minimum width, in columns, of the column at `index`.
+    pub fn min_width(mut self, index: usize, width: usize) -> Self {
+        self.column_mut(index).min_width = width;
+        self
+    }
+
+    /// Sets the maximum width, in columns, of the column at `index`.
+    pub fn max_width(mut self, index: usize, width: usize) -> Self {
+        self.column_mut(index).max_width = Some(width.max(1));
+        self
+    }
+
+    /// Sets how content wider than the column at `index` is handled.
+    pub fn overflow(mut self, index: usize, overflow: Overflow) -> Self {
+        self.column_mut(index).overflow = overflow;
+        self
+    }
+
+    fn column_mut(&mut self, index: usize) -> &mut Column {
+        if self.columns.len() <= index {
+            self.columns.resize(index + 1, Column::default());
+        }
+        &mut self.columns[index]
+    }
+
+    fn column_count(&self) -> usize {
+        self.rows.iter().map(Vec::len).chain(Some(self.headers.len())).max().unwrap_or(0)
+    }
+
+    fn widths(&self, count: usize, border: Border) -> Vec<usize> {
+        (0..count).map(|i| {
+            let column = self.columns.get(i).copied().unwrap_or_default();
+            let natural = self.rows.iter()
+                .filter_map(|row| row.get(i))
+                .chain(self.headers.get(i))
+                .flat_map(|cell| cell.text(border).split('\n').map(text::display_width).collect::<Vec<_>>())
+                .max()
+                .unwrap_or(0);
+            let min = if border == Border::Markdown { column.min_width.max(3) } else { column.min_width };
+            let width = natural.max(min);
+            column.max_width.map_or(width, |max| width.min(max.max(min)))
+        }).collect()
+    }
+
+    /// Lays out one row as lines of padded, styled cells.
+    fn layout(&self, cells: &[Cell], widths: &[usize], defaults: &[Style], border: Border) -> Vec<Vec<String>> {
+        let ellipsis = Utf8::HorizontalEllipsis.to_string();
+        let empty = Cell::default();
+        let columns: Vec<Vec<String>> = widths.iter().enumerate().map(|(i, &width)| {
+            let column = self.columns.get(i).copied().unwrap_or_default();
+            let cell = cells.get(i).unwrap_or(&empty);
+            cell.text(border).split('\n')
+                .flat_map(|line| {
+                    if text::display_width(line) <= width {
+                        vec![line.to_string()]
+                    } else {
+                        match column.overflow {
+                            Overflow::Wrap => text::wrap(line, width),
+                            Overflow::Truncate => vec![text::truncate(line, width, &ellipsis)],
+                        }
+                    }
+                })
+                .collect()
+        }).collect();
+
+        let height = columns.iter().map(Vec::len).max().unwrap_or(1);
+        (0..height).map(|line| {
+            widths.iter().enumerate().map(|(i, &width)| {
+                let column = self.columns.get(i).copied().unwrap_or_default();
+                let cell = cells.get(i).unwrap_or(&empty);
+                let content = columns[i].get(line).map_or("", String::as_str);
+                let padded = text::pad(content, width, cell.align.unwrap_or(column.align));
+                let styles = if cell.styles.is_empty() { defaults } else { &cell.styles };
+
+                match border {
+                    Border::None if styles.is_empty() => padded,
+                    Border::None => style(styles.iter().copied(), padded),
+                    Border::Markdown => format!(" {} ", padded),
+                    _ if styles.is_empty() => format!(" {} ", padded),
+                    _ => style(styles.iter().copied(), format!(" {} ", padded)),
+                }
+            }).collect()
+        }).collect()
+    }
+}
+
+impl std::fmt::Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let count = self.column_count();
+        if count == 0 {
+            return Ok(());
+        }
+        let border = self.border.resolve();
+        let widths = self.widths(count, border);
+        let boxed = !matches!(border, Border::None | Border::Markdown);
+
+        let rule = |f: &mut std::fmt::Formatter, up: bool, down: bool| -> std::fmt::Result {
+            let horizontal = border.glyph(false, true, false, true).to_string();
+            write!(f, "{}", border.glyph(up, true, down, false))?;
+            for (i, width) in widths.iter().enumerate() {
+                if i > 0 {
+                    write!(f, "{}", border.glyph(up, true, down, true))?;
+                }
+                write!(f, "{}", horizontal.repeat(width + 2))?;
+            }
+            writeln!(f, "{}", border.glyph(up, false, down, true))
+        };
+        let line = |f: &mut std::fmt::Formatter, cells: Vec<String>| -> std::fmt::Result {
+            if border == Border::None {
+                return writeln!(f, "{}", cells.join("  ").trim_end());
+            }
+            let vertical = border.glyph(true, false, true, false);
+            write!(f, "{}", vertical)?;
+            for cell in cells {
+                write!(f, "{}{}", cell, vertical)?;
+            }
+            writeln!(f)
+        };
+
+        if boxed {
+            rule(f, false, true)?;
+        }
+        if !self.headers.is_empty() {
+            for cells in self.layout(&self.headers, &widths, &self.header_style, border) {
+                line(f, cells)?;
+            }
+            if boxed {
+                rule(f, true, true)?;
+            } else if border == Border::Markdown {
+                let separators = widths.iter().enumerate().map(|(i, &width)| {
+                    match self.columns.get(i).map_or(Align::Left, |c| c.align) {
+                        Align::Left => format!(" {} ", "-".repeat(width)),
+                        Align::Center => format!(" :{}: ", "-".repeat(width - 2)),
+                        Align::Right => format!(" {}: ", "-".repeat(width - 1)),
+                    }
+                }).collect();
+                line(f, separators)?;
+            }
+        }
+        for row in &self.rows {
+            for cells in self.layout(row, &widths, &self.row_style, border) {
+                line(f, cells)?;
+            }
+        }
+        if boxed {
+            rule(f, true, false)?;
+        }
+        Ok(())
+    }
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_styled_cells_align() {
+        let table = Table::new()
+            .headers(["State"])
+            .row([Cell::new("ok").style([Style::FGGreen])])
+            .row([style([Style::FGRed], "failed")])
+            .border(Border::Ascii);
+        assert_eq!(table.to_string(), format!(
+            "+--------+\n| State  |\n+--------+\n{}\n|{}|\n+--------+\n",
+            format_args!("|{}|", "\x1b[32m ok     \x1b[0m"),
+            " \x1b[31mfailed\x1b[0m ",
+        ));
+    }
+
+    #[test]
+    fn test_wrap_and_truncate() {
+        let table = Table::new()
+            .row(["one two three", "abcdefgh"])
+            .max_width(0, 7)
+            .max_width(1, 4)
+            .overflow(1, Overflow::Truncate)
+            .border(Border::Rounded);
+        assert_eq!(table.to_string(), "\
+╭─────────┬──────╮
+│ one two │ abc… │
+│ three   │      │
+╰─────────┴──────╯
+");
+    }
+
+    #[test]
+    fn test_borderless() {
+        let table = Table::new()
+            .headers(["a", "b"])
+            .row(["long", "x"])
+            .border(Border::None);
+        assert_eq!(table.to_string(), "a     b\nlong  x\n");
+
+        let styled = Table::new()
+            .headers(["a", "b"])
+            .header_style([Style::Bold])
+            .row([Cell::new("x").style([Style::FGRed]), Cell::new("y")])
+            .border(Border::None);
+        assert_eq!(styled.to_string(), "\x1b[1ma\x1b[0m  \x1b[1mb\x1b[0m\n\x1b[31mx\x1b[0m  y\n");
+    }
+
+    #[test]
+    fn test_markdown_escapes_pipes() {
+        let table = Table::new().headers(["op"]).row(["a|b"]).border(Border::Markdown);
+        assert_eq!(table.to_string(), "| op   |\n| ---- |\n| a\\|b |\n");
+    }
+
+    #[test]
+    fn test_markdown_joins_lines() {
+        let table = Table::new().border(Border::Markdown).headers(["a", "b"]).row(["line1\nline2", "x"]);
+        assert_eq!(table.to_string(), "| a              | b   |\n| -------------- | --- |\n| line1<br>line2 | x   |\n");
+    }
+}
@@ -0,0 +1,835 @@
+//! Measuring and laying out text that may contain ANSI escape sequences.
+//!
+//! Strings produced by `style()`, `styler()` and `style!()` carry escape codes that take up bytes
+//! but no columns on screen, while some characters (CJK, emoji) take up two columns. The functions
+//! in this module measure text by its display width so styled output can be aligned correctly.
+
+use crate::characters::Utf8;
+
+/// Horizontal alignment of text within a fixed width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Align {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// A piece of text: either an escape sequence or a single grapheme (a character together with any
+/// combining characters following it).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    Escape(&'a str),
+    Grapheme(&'a str),
+}
+
+/// Splits `s` into escape sequences and graphemes.
+pub(crate) fn tokens(s: &str) -> Tokens<'_> {
+    Tokens { rest: s }
+}
+
+pub(crate) struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let s = self.rest;
+        let mut chars = s.char_indices();
+        let (_, first) = chars.next()?;
+
+        let end = if first == '\x1b' {
+            escape_len(s)
+        } else {
+            // Extend the grapheme over zero-width characters and combining marks, over the
+            // character following a zero-width joiner or the consonant following a virama, and
+            // pair regional indicators into flags
+            let mut end = first.len_utf8();
+            let (mut joined, mut linked, mut flag) = (false, false, is_regional_indicator(first));
+            for (i, c) in chars {
+                let extends = joined
+                    || (linked && c.is_alphabetic() && !c.is_ascii())
+                    || (flag && is_regional_indicator(c))
+                    || is_zero_width(c);
+                if !extends {
+                    break;
+                }
+                joined = c == '\u{200D}';
+                linked = is_virama(c);
+                flag = false;
+                end = i + c.len_utf8();
+            }
+            end
+        };
+
+        self.rest = &s[end..];
+        if first == '\x1b' {
+            Some(Token::Escape(&s[..end]))
+        } else {
+            Some(Token::Grapheme(&s[..end]))
+        }
+    }
+}
+
+/// Returns the length in bytes of the escape sequence at the start of `s`.
+fn escape_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    match bytes.get(1) {
+        // CSI: parameters and intermediates followed by a final byte in `@..=~`
+        Some(b'[') => bytes[2..].iter()
+            .position(|b| (0x40..=0x7E).contains(b))
+            .map_or(bytes.len(), |i| i + 3),
+        // OSC: terminated by BEL or ST (`ESC \`)
+        Some(b']') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                if bytes[i] == 0x07 {
+                    return i + 1;
+                }
+                if bytes[i] == 0x1b && bytes.get(i + 1) == Some(&b'\\') {
+                    return i + 2;
+                }
+                i += 1;
+            }
+            bytes.len()
+        },
+        Some(_) => 1 + s[1..].chars().next().map_or(0, char::len_utf8),
+        None => 1,
+    }
+}
+
+/// Ranges of combining marks (general categories Mn, Mc and Me, Unicode 14), which extend the
+/// grapheme before them.
+const COMBINING_MARKS: &[(u32, u32)] = &[
+    (0x0300, 0x036F), (0x0483, 0x0489), (0x0591, 0x05BD), (0x05BF, 0x05BF), (0x05C1, 0x05C2), (0x05C4, 0x05C5),
+    (0x05C7, 0x05C7), (0x0610, 0x061A), (0x064B, 0x065F), (0x0670, 0x0670), (0x06D6, 0x06DC), (0x06DF, 0x06E4),
+    (0x06E7, 0x06E8), (0x06EA, 0x06ED), (0x0711, 0x0711), (0x0730, 0x074A), (0x07A6, 0x07B0), (0x07EB, 0x07F3),
+    (0x07FD, 0x07FD), (0x0816, 0x0819), (0x081B, 0x0823), (0x0825, 0x0827), (0x0829, 0x082D), (0x0859, 0x085B),
+    (0x0898, 0x089F), (0x08CA, 0x08E1), (0x08E3, 0x0903), (0x093A, 0x093C), (0x093E, 0x094F), (0x0951, 0x0957),
+    (0x0962, 0x0963), (0x0981, 0x0983), (0x09BC, 0x09BC), (0x09BE, 0x09C4), (0x09C7, 0x09C8), (0x09CB, 0x09CD),
+    (0x09D7, 0x09D7), (0x09E2, 0x09E3), (0x09FE, 0x09FE), (0x0A01, 0x0A03), (0x0A3C, 0x0A3C), (0x0A3E, 0x0A42),
+    (0x0A47, 0x0A48), (0x0A4B, 0x0A4D), (0x0A51, 0x0A51), (0x0A70, 0x0A71), (0x0A75, 0x0A75), (0x0A81, 0x0A83),
+    (0x0ABC, 0x0ABC), (0x0ABE, 0x0AC5), (0x0AC7, 0x0AC9), (0x0ACB, 0x0ACD), (0x0AE2, 0x0AE3), (0x0AFA, 0x0AFF),
+    (0x0B01, 0x0B03), (0x0B3C, 0x0B3C), (0x0B3E, 0x0B44), (0x0B47, 0x0B48), (0x0B4B, 0x0B4D), (0x0B55, 0x0B57),
+    (0x0B62, 0x0B63), (0x0B82, 0x0B82), (0x0BBE, 0x0BC2), (0x0BC6, 0x0BC8), (0x0BCA, 0x0BCD), (0x0BD7, 0x0BD7),
+    (0x0C00, 0x0C04), (0x0C3C, 0x0C3C), (0x0C3E, 0x0C44), (0x0C46, 0x0C48), (0x0C4A, 0x0C4D), (0x0C55, 0x0C56),
+    (0x0C62, 0x0C63), (0x0C81, 0x0C83), (0x0CBC, 0x0CBC), (0x0CBE, 0x0CC4), (0x0CC6, 0x0CC8), (0x0CCA, 0x0CCD),
+    (0x0CD5, 0x0CD6), (0x0CE2, 0x0CE3), (0x0D00, 0x0D03), (0x0D3B, 0x0D3C), (0x0D3E, 0x0D44), (0x0D46, 0x0D48),
+    (0x0D4A, 0x0D4D), (0x0D57, 0x0D57), (0x0D62, 0x0D63), (0x0D81, 0x0D83), (0x0DCA, 0x0DCA), (0x0DCF, 0x0DD4),
+    (0x0DD6, 0x0DD6), (0x0DD8, 0x0DDF), (0x0DF2, 0x0DF3), (0x0E31, 0x0E31), (0x0E34, 0x0E3A), (0x0E47, 0x0E4E),
+    (0x0EB1, 0x0EB1), (0x0EB4, 0x0EBC), (0x0EC8, 0x0ECD), (0x0F18, 0x0F19), (0x0F35, 0x0F35), (0x0F37, 0x0F37),
+    (0x0F39, 0x0F39), (0x0F3E, 0x0F3F), (0x0F71, 0x0F84), (0x0F86, 0x0F87), (0x0F8D, 0x0F97), (0x0F99, 0x0FBC),
+    (0x0FC6, 0x0FC6), (0x102B, 0x103E), (0x1056, 0x1059), (0x105E, 0x1060), (0x1062, 0x1064), (0x1067, 0x106D),
+    (0x1071, 0x1074), (0x1082, 0x108D), (0x108F, 0x108F), (0x109A, 0x109D), (0x135D, 0x135F), (0x1712, 0x1715),
+    (0x1732, 0x1734), (0x1752, 0x1753), (0x1772, 0x1773), (0x17B4, 0x17D3), (0x17DD, 0x17DD), (0x180B, 0x180D),
+    (0x180F, 0x180F), (0x1885, 0x1886), (0x18A9, 0x18A9), (0x1920, 0x192B), (0x1930, 0x193B), (0x1A17, 0x1A1B),
+    (0x1A55, 0x1A5E), (0x1A60, 0x1A7C), (0x1A7F, 0x1A7F), (0x1AB0, 0x1ACE), (0x1B00, 0x1B04), (0x1B34, 0x1B44),
+    (0x1B6B, 0x1B73), (0x1B80, 0x1B82), (0x1BA1, 0x1BAD), (0x1BE6, 0x1BF3), (0x1C24, 0x1C37), (0x1CD0, 0x1CD2),
+    (0x1CD4, 0x1CE8), (0x1CED, 0x1CED), (0x1CF4, 0x1CF4), (0x1CF7, 0x1CF9), (0x1DC0, 0x1DFF), (0x20D0, 0x20F0),
+    (0x2CEF, 0x2CF1), (0x2D7F, 0x2D7F), (0x2DE0, 0x2DFF), (0x302A, 0x302F), (0x3099, 0x309A), (0xA66F, 0xA672),
+    (0xA674, 0xA67D), (0xA69E, 0xA69F), (0xA6F0, 0xA6F1), (0xA802, 0xA802), (0xA806, 0xA806), (0xA80B, 0xA80B),
+    (0xA823, 0xA827), (0xA82C, 0xA82C), (0xA880, 0xA881), (0xA8B4, 0xA8C5), (0xA8E0, 0xA8F1), (0xA8FF, 0xA8FF),
+    (0xA926, 0xA92D), (0xA947, 0xA953), (0xA980, 0xA983), (0xA9B3, 0xA9C0), (0xA9E5, 0xA9E5), (0xAA29, 0xAA36),
+    (0xAA43, 0xAA43), (0xAA4C, 0xAA4D), (0xAA7B, 0xAA7D), (0xAAB0, 0xAAB0), (0xAAB2, 0xAAB4), (0xAAB7, 0xAAB8),
+    (0xAABE, 0xAABF), (0xAAC1, 0xAAC1), (0xAAEB, 0xAAEF), (0xAAF5, 0xAAF6), (0xABE3, 0xABEA), (0xABEC, 0xABED),
+    (0xFB1E, 0xFB1E), (0xFE00, 0xFE0F), (0xFE20, 0xFE2F), (0x101FD, 0x101FD), (0x102E0, 0x102E0), (0x10376, 0x1037A),
+    (0x10A01, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A0F), (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F), (0x10AE5, 0x10AE6),
+    (0x10D24, 0x10D27), (0x10EAB, 0x10EAC), (0x10F46, 0x10F50), (0x10F82, 0x10F85), (0x11000, 0x11002), (0x11038, 0x11046),
+    (0x11070, 0x11070), (0x11073, 0x11074), (0x1107F, 0x11082), (0x110B0, 0x110BA), (0x110C2, 0x110C2), (0x11100, 0x11102),
+    (0x11127, 0x11134), (0x11145, 0x11146), (0x11173, 0x11173), (0x11180, 0x11182), (0x111B3, 0x111C0), (0x111C9, 0x111CC),
+    (0x111CE, 0x111CF), (0x1122C, 0x11237), (0x1123E, 0x1123E), (0x112DF, 0x112EA), (0x11300, 0x11303), (0x1133B, 0x1133C),
+    (0x1133E, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134D), (0x11357, 0x11357), (0x11362, 0x11363), (0x11366, 0x1136C),
+    (0x11370, 0x11374), (0x11435, 0x11446), (0x1145E, 0x1145E), (0x114B0, 0x114C3), (0x115AF, 0x115B5), (0x115B8, 0x115C0),
+    (0x115DC, 0x115DD), (0x11630, 0x11640), (0x116AB, 0x116B7), (0x1171D, 0x1172B), (0x1182C, 0x1183A), (0x11930, 0x11935),
+    (0x11937, 0x11938), (0x1193B, 0x1193E), (0x11940, 0x11940), (0x11942, 0x11943), (0x119D1, 0x119D7), (0x119DA, 0x119E0),
+    (0x119E4, 0x119E4), (0x11A01, 0x11A0A), (0x11A33, 0x11A39), (0x11A3B, 0x11A3E), (0x11A47, 0x11A47), (0x11A51, 0x11A5B),
+    (0x11A8A, 0x11A99), (0x11C2F, 0x11C36), (0x11C38, 0x11C3F), (0x11C92, 0x11CA7), (0x11CA9, 0x11CB6), (0x11D31, 0x11D36),
+    (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D), (0x11D3F, 0x11D45), (0x11D47, 0x11D47), (0x11D8A, 0x11D8E), (0x11D90, 0x11D91),
+    (0x11D93, 0x11D97), (0x11EF3, 0x11EF6), (0x16AF0, 0x16AF4), (0x16B30, 0x16B36), (0x16F4F, 0x16F4F), (0x16F51, 0x16F87),
+    (0x16F8F, 0x16F92), (0x16FE4, 0x16FE4), (0x16FF0, 0x16FF1), (0x1BC9D, 0x1BC9E), (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46),
+    (0x1D165, 0x1D169), (0x1D16D, 0x1D172), (0x1D17B, 0x1D182), (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244),
+    (0x1DA00, 0x1DA36), (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84), (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF),
+    (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021), (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E130, 0x1E136),
+    (0x1E2AE, 0x1E2AE), (0x1E2EC, 0x1E2EF), (0x1E8D0, 0x1E8D6), (0x1E944, 0x1E94A), (0xE0100, 0xE01EF),
+];
+
+fn is_combining_mark(c: char) -> bool {
+    let c = c as u32;
+    COMBINING_MARKS
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+fn is_zero_width(c: char) -> bool {
+    is_combining_mark(c) || matches!(c as u32,
+        0x200B..=0x200F | 0x2028..=0x202E | 0x2060..=0x2064 | 0xFEFF | 0x1F3FB..=0x1F3FF | 0xE0000..=0xE0FFF
+    )
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c as u32, 0x1F1E6..=0x1F1FF)
+}
+
+/// Returns `true` for the viramas of the Indic scripts, which join the consonant after them into
+/// a conjunct.
+fn is_virama(c: char) -> bool {
+    matches!(c, '\u{094D}' | '\u{09CD}' | '\u{0ACD}' | '\u{0B4D}' | '\u{0C4D}' | '\u{0D4D}')
+}
+
+fn is_wide(c: char) -> bool {
+    matches!(c as u32,
+        0x1100..=0x115F | 0x231A..=0x231B | 0x2329..=0x232A | 0x23E9..=0x23EC | 0x23F0 | 0x23F3
+        | 0x25FD..=0x25FE | 0x2614..=0x2615 | 0x2648..=0x2653 | 0x267F | 0x2693 | 0x26A1
+        | 0x26AA..=0x26AB | 0x26BD..=0x26BE | 0x26C4..=0x26C5 | 0x26CE | 0x26D4 | 0x26EA
+        | 0x26F2..=0x26F3 | 0x26F5 | 0x26FA | 0x26FD | 0x2705 | 0x270A..=0x270B | 0x2728 | 0x274C
+        | 0x274E | 0x2753..=0x2755 | 0x2757 | 0x2795..=0x2797 | 0x27B0 | 0x27BF | 0x2B1B..=0x2B1C
+        | 0x2B50 | 0x2B55 | 0x2E80..=0x303E | 0x3041..=0x33FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF | 0xA960..=0xA97F | 0xAC00..=0xD7A3 | 0xF900..=0xFAFF | 0xFE10..=0xFE19
+        | 0xFE30..=0xFE6F | 0xFF00..=0xFF60 | 0xFFE0..=0xFFE6 | 0x1F004 | 0x1F0CF | 0x1F18E
+        | 0x1F191..=0x1F19A | 0x1F200..=0x1F251 | 0x1F300..=0x1F64F | 0x1F680..=0x1F6FF
+        | 0x1F7E0..=0x1F7EB | 0x1F90C..=0x1F9FF | 0x1FA70..=0x1FAFF | 0x20000..=0x2FFFD
+        | 0x30000..=0x3FFFD
+    )
+}
+
+/// Returns the number of terminal columns `c` occupies: `0` for control and combining characters,
+/// `2` for wide East Asian characters and emoji, and `1` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::text::char_width;
+///
+/// assert_eq!(char_width('a'), 1);
+/// assert_eq!(char_width('│'), 1);
+/// assert_eq!(char_width('漢'), 2);
+/// assert_eq!(char_width('\u{0301}'), 0);
+/// ```
+///
+pub fn char_width(c: char) -> usize {
+    if c.is_control() || is_zero_width(c) {
+        0
+    } else if is_wide(c) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Returns the width of a single grapheme, treating emoji presentation sequences and flags as wide.
+pub(crate) fn grapheme_width(g: &str) -> usize {
+    let mut chars = g.chars();
+    let width = chars.next().map_or(0, char_width);
+    // Emoji presentation selectors and pairs of regional indicators (flags) are wide
+    if width == 1 && chars.any(|c| c == '\u{FE0F}' || is_regional_indicator(c)) { 2 } else { width }
+}
+
+/// Returns the number of terminal columns `s` occupies, ignoring ANSI escape sequences.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+/// use dekor::text::display_width;
+///
+/// let styled = style!(Bold, FGRed => "error");
+/// assert_eq!(styled.len(), 16);
+/// assert_eq!(display_width(&styled), 5);
+/// assert_eq!(display_width("日本"), 4);
+/// ```
+///
+pub fn display_width(s: &str) -> usize {
+    tokens(s)
+        .map(|t| match t {
+            Token::Grapheme(g) => grapheme_width(g),
+            Token::Escape(_) => 0,
+        })
+        .sum()
+}
+
+/// Removes every ANSI escape sequence from `s`, leaving only the visible text.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+/// use dekor::text::strip_ansi;
+///
+/// assert_eq!(strip_ansi(&style!(Underline, FGBlue => "link")), "link");
+/// ```
+///
+pub fn strip_ansi(s: &str) -> String {
+    tokens(s)
+        .filter_map(|t| match t {
+            Token::Grapheme(g) => Some(g),
+            Token::Escape(_) => None,
+        })
+        .collect()
+}
+
+/// Returns `true` for SGR sequences (`ESC [ ... m`).
+pub(crate) fn is_sgr(escape: &str) -> bool {
+    escape.starts_with("\x1b[") && escape.ends_with('m')
+}
+
+/// Returns `true` for SGR sequences that reset every attribute (`ESC [ m` and `ESC [ 0 m`).
+pub(crate) fn is_reset(escape: &str) -> bool {
+    escape == "\x1b[0m" || escape == "\x1b[m"
+}
+
+/// Tracks the SGR sequences and the OSC 8 hyperlink in effect so they can be closed and re-opened
+/// around a break.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ActiveStyle {
+    codes: String,
+    link: String,
+}
+
+impl ActiveStyle {
+    pub(crate) fn update(&mut self, escape: &str) {
+        if is_reset(escape) {
+            self.codes.clear();
+        } else if is_sgr(escape) {
+            self.codes.push_str(escape);
+        } else if let Some(link) = escape.strip_prefix("\x1b]8;") {
+            // `ESC ] 8 ; params ; url ST` opens a link, and an empty url closes it
+            let url = link.split_once(';').map_or("", |(_, url)| url.trim_end_matches(['\x07', '\\', '\x1b']));
+            self.link = if url.is_empty() { String::new() } else { escape.to_string() };
+        }
+    }
+
+    /// The sequences needed to re-open the active style.
+    pub(crate) fn open(&self) -> String {
+        format!("{}{}", self.codes, self.link)
+    }
+
+    /// The sequences needed to close the active style, empty if nothing is active.
+    pub(crate) fn close(&self) -> String {
+        let reset = if self.codes.is_empty() { "" } else { "\x1b[0m" };
+        let link = if self.link.is_empty() { "" } else { "\x1b]8;;\x1b\\" };
+        format!("{}{}", reset, link)
+    }
+}
+
+/// Pads `s` with spaces to `width` columns according to `align`. Text already at least `width`
+/// columns wide is returned unchanged.
+pub(crate) fn pad(s: &str, width: usize, align: Align) -> String {
+    let missing = width.saturating_sub(display_width(s));
+    let (left, right) = match align {
+        Align::Left => (0, missing),
+        Align::Right => (missing, 0),
+        Align::Center => (missing / 2, missing - missing / 2),
+    };
+    format!("{}{}{}", " ".repeat(left), s, " ".repeat(right))
+}
+
+/// Shortens `s` to at most `width` columns, ending it with `ellipsis` when anything was cut.
+///
+/// Escape sequences are never split and a style left open by the cut is closed.
+pub(crate) fn truncate(s: &str, width: usize, ellipsis: &str) -> String {
+    if display_width(s) <= width {
+        return s.to_string();
+    }
+
+    let ellipsis_width = display_width(ellipsis);
+    let (budget, ellipsis) = if ellipsis_width <= width { (width - ellipsis_width, ellipsis) } else { (width, "") };
+
+    let mut out = String::with_capacity(s.len());
+    let mut active = ActiveStyle::default();
+    let mut used = 0;
+    for token in tokens(s) {
+        match token {
+            Token::Escape(e) => {
+                active.update(e);
+                out.push_str(e);
+            },
+            Token::Grapheme(g) => {
+                let w = grapheme_width(g);
+                if used + w > budget {
+                    break;
+                }
+                used += w;
+                out.push_str(g);
+            },
+        }
+    }
+    out.push_str(ellipsis);
+    out.push_str(&active.close());
+    out
+}
+
+/// Pads `s` with spaces on the left to `width` columns, aligning it to the right.
+///
+/// Width is measured in display columns, ignoring escape sequences, so styled strings from
+/// `style()` and styled values such as `Painted` line up like plain text.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+/// use dekor::text::{center, pad_left, pad_right};
+///
+/// let ok = style!(FGGreen => "ok");
+/// assert_eq!(pad_left(&ok, 4), format!("  {}", ok));
+/// assert_eq!(pad_right(paint([Style::Bold], &"日本"), 6), "\x1b[1m日本\x1b[0m  ");
+/// assert_eq!(center("ab", 5), " ab  ");
+/// ```
+///
+pub fn pad_left<D: std::fmt::Display>(s: D, width: usize) -> String {
+    pad(&s.to_string(), width, Align::Right)
+}
+
+/// Pads `s` with spaces on the right to `width` columns, aligning it to the left. See `pad_left`.
+pub fn pad_right<D: std::fmt::Display>(s: D, width: usize) -> String {
+    pad(&s.to_string(), width, Align::Left)
+}
+
+/// Pads `s` with spaces on both sides to `width` columns, the extra space going to the right. See
+/// `pad_left`.
+pub fn center<D: std::fmt::Display>(s: D, width: usize) -> String {
+    pad(&s.to_string(), width, Align::Center)
+}
+
+/// Shortens `s` to at most `width` columns, ending it with `Utf8::HorizontalEllipsis` when
+/// anything was cut.
+///
+/// The cut never falls inside an escape sequence or a grapheme such as an emoji sequence, and a
+/// style or hyperlink left open by the cut is closed after the ellipsis.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+/// use dekor::text::truncate_with_ellipsis;
+///
+/// assert_eq!(truncate_with_ellipsis(style!(Bold => "Hello, world!"), 6), "\x1b[1mHello…\x1b[0m");
+/// assert_eq!(truncate_with_ellipsis("👩‍💻 coding", 3), "👩‍💻…");
+/// assert_eq!(truncate_with_ellipsis("short", 6), "short");
+///
+/// // Flags and Devanagari conjuncts are kept whole
+/// assert_eq!(truncate_with_ellipsis("🇺🇸🇫🇷x", 3), "🇺🇸…");
+/// assert_eq!(truncate_with_ellipsis("🇺🇸🇫🇷x", 4), "🇺🇸…");
+/// assert_eq!(truncate_with_ellipsis("स्ते", 1), "स्ते");
+/// assert_eq!(truncate_with_ellipsis("नमस्ते", 2), "न…");
+/// ```
+///
+pub fn truncate_with_ellipsis<D: std::fmt::Display>(s: D, width: usize) -> String {
+    truncate(&s.to_string(), width, &Utf8::HorizontalEllipsis.to_string())
+}
+
+/// How `wrap` chooses where to break lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WrapAlgorithm {
+    /// Puts as many words on each line as fit
+    #[default]
+    Greedy,
+    /// Chooses the breaks that make lines the most even, minimizing the sum of the squared space
+    /// left at the end of every line but the last of each paragraph
+    MinRaggedness,
+}
+
+/// The width and layout of wrapped text, see `wrap`.
+///
+/// A `usize` converts into the options for that width with no prefix or indentation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WrapOptions {
+    width: usize,
+    algorithm: WrapAlgorithm,
+    prefix: String,
+    initial_indent: String,
+    subsequent_indent: String,
+}
+
+impl WrapOptions {
+    /// Creates options for lines of at most `width` columns, prefix and indentation included.
+    pub fn new(width: usize) -> Self {
+        WrapOptions {
+            width,
+            algorithm: WrapAlgorithm::Greedy,
+            prefix: String::new(),
+            initial_indent: String::new(),
+            subsequent_indent: String::new(),
+        }
+    }
+
+    /// Sets the algorithm, `WrapAlgorithm::Greedy` by default.
+    pub fn algorithm(mut self, algorithm: WrapAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Sets text written at the start of every line, such as `│ ` or `> `. It may be styled.
+    pub fn prefix<D: std::fmt::Display>(mut self, prefix: D) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Sets text written after the prefix on the first line, such as a bullet.
+    pub fn initial_indent<D: std::fmt::Display>(mut self, indent: D) -> Self {
+        self.initial_indent = indent.to_string();
+        self
+    }
+
+    /// Sets text written after the prefix on every line but the first, for hanging indents.
+    pub fn subsequent_indent<D: std::fmt::Display>(mut self, indent: D) -> Self {
+        self.subsequent_indent = indent.to_string();
+        self
+    }
+
+    /// Returns the columns left for text on the first and the following lines.
+    fn capacities(&self) -> (usize, usize) {
+        let prefix = display_width(&self.prefix);
+        let capacity = |indent: &str| self.width.saturating_sub(prefix + display_width(indent)).max(1);
+        (capacity(&self.initial_indent), capacity(&self.subsequent_indent))
+    }
+}
+
+impl From<usize> for WrapOptions {
+    fn from(width: usize) -> Self {
+        WrapOptions::new(width)
+    }
+}
+
+/// A word of the input: its tokens and its display width.
+struct Word<'a> {
+    tokens: Vec<Token<'a>>,
+    width: usize,
+    /// Whether the word continues the previous one, split for being too long
+    joined: bool,
+}
+
+impl Word<'_> {
+    fn new() -> Self {
+        Word { tokens: Vec::new(), width: 0, joined: false }
+    }
+}
+
+/// Splits a line into words, attaching escape sequences between words to the preceding word.
+fn words(s: &str) -> (Vec<Word<'_>>, Vec<Token<'_>>) {
+    let mut words: Vec<Word> = Vec::new();
+    let mut leading = Vec::new();
+    let mut current = Word::new();
+    for token in tokens(s) {
+        match token {
+            Token::Grapheme(g) if g.chars().all(char::is_whitespace) => {
+                if current.width > 0 {
+                    words.push(std::mem::replace(&mut current, Word::new()));
+                }
+            },
+            Token::Grapheme(g) => {
+                current.width += grapheme_width(g);
+                current.tokens.push(token);
+            },
+            // Resets after whitespace close the previous word, anything else opens the next one so
+            // the joining space keeps the style in effect before it
+            Token::Escape(escape) if current.tokens.is_empty() && is_reset(escape) => match words.last_mut() {
+                Some(word) => word.tokens.push(token),
+                None => leading.push(token),
+            },
+            Token::Escape(_) => current.tokens.push(token),
+        }
+    }
+    if current.width > 0 {
+        words.push(current);
+    } else {
+        match words.last_mut() {
+            Some(word) => word.tokens.extend(current.tokens),
+            None => leading.extend(current.tokens),
+        }
+    }
+    (words, leading)
+}
+
+/// Splits words wider than `width` into joined pieces that fit.
+fn split_long(words: Vec<Word<'_>>, width: usize) -> Vec<Word<'_>> {
+    let mut out = Vec::with_capacity(words.len());
+    for word in words {
+        if word.width <= width {
+            out.push(word);
+            continue;
+        }
+        let mut piece = Word { joined: word.joined, ..Word::new() };
+        for token in word.tokens {
+            if let Token::Grapheme(g) = token {
+                if piece.width > 0 && piece.width + grapheme_width(g) > width {
+                    out.push(std::mem::replace(&mut piece, Word { joined: true, ..Word::new() }));
+                }
+                piece.width += grapheme_width(g);
+            }
+            piece.tokens.push(token);
+        }
+        out.push(piece);
+    }
+    out
+}
+
+/// Builds wrapped lines, closing the active style at the end of each line and re-opening it at
+/// the start of the next.
+struct LineBuilder {
+    lines: Vec<String>,
+    line: String,
+    width: usize,
+    active: ActiveStyle,
+    capacities: (usize, usize),
+}
+
+impl LineBuilder {
+    fn new(capacities: (usize, usize)) -> Self {
+        LineBuilder { lines: Vec::new(), line: String::new(), width: 0, active: ActiveStyle::default(), capacities }
+    }
+
+    /// Returns the width available on the current line.
+    fn capacity(&self) -> usize {
+        if self.lines.is_empty() { self.capacities.0 } else { self.capacities.1 }
+    }
+
+    fn push(&mut self, token: Token) {
+        match token {
+            Token::Escape(e) => {
+                self.active.update(e);
+                self.line.push_str(e);
+            },
+            Token::Grapheme(g) => {
+                self.width += grapheme_width(g);
+                self.line.push_str(g);
+            },
+        }
+    }
+
+    fn space(&mut self) {
+        self.line.push(' ');
+        self.width += 1;
+    }
+
+    fn break_line(&mut self) {
+        self.line.push_str(&self.active.close());
+        let next = self.active.open();
+        self.lines.push(std::mem::replace(&mut self.line, next));
+        self.width = 0;
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        self.lines.push(self.line);
+        self.lines
+    }
+}
+
+/// Fills each line with as many words as fit, splitting words longer than a line.
+fn greedy(words: Vec<Word>, builder: &mut LineBuilder) {
+    for word in words {
+        if builder.width > 0 {
+            if builder.width + 1 + word.width <= builder.capacity() {
+                builder.space();
+            } else {
+                builder.break_line();
+            }
+        }
+        for token in word.tokens {
+            if let Token::Grapheme(g) = token {
+                if builder.width > 0 && builder.width + grapheme_width(g) > builder.capacity() {
+                    builder.break_line();
+                }
+            }
+            builder.push(token);
+        }
+    }
+}
+
+/// Breaks a paragraph where the total squared slack of its lines, the last excepted, is smallest.
+fn min_raggedness(words: Vec<Word>, builder: &mut LineBuilder) {
+    let (first, rest) = (builder.capacity(), builder.capacities.1);
+    let words = split_long(words, first.min(rest));
+    let n = words.len();
+
+    // `cost[j]` is the best cost of laying out the words from `j` on, whose first line ends
+    // before `next[j]`
+    let mut cost = vec![u64::MAX; n + 1];
+    let mut next = vec![n; n + 1];
+    cost[n] = 0;
+    for j in (0..n).rev() {
+        let capacity = if j == 0 { first } else { rest };
+        let mut width = 0;
+        for k in j + 1..=n {
+            let word = &words[k - 1];
+            width += word.width + usize::from(k - 1 > j && !word.joined);
+            if width > capacity && k > j + 1 {
+                break;
+            }
+            let slack = capacity.saturating_sub(width) as u64;
+            let line = if k == n { 0 } else { slack * slack };
+            if line.saturating_add(cost[k]) < cost[j] {
+                cost[j] = line.saturating_add(cost[k]);
+                next[j] = k;
+            }
+        }
+    }
+
+    let mut j = 0;
+    let mut words = words.into_iter();
+    while j < n {
+        if j > 0 {
+            builder.break_line();
+        }
+        for (i, word) in words.by_ref().take(next[j] - j).enumerate() {
+            if i > 0 && !word.joined {
+                builder.space();
+            }
+            word.tokens.into_iter().for_each(|t| builder.push(t));
+        }
+        j = next[j];
+    }
+}
+
+/// Wraps `s` into lines of at most `options.width` columns, measured by display width.
+///
+/// Each line of `s` is a paragraph: runs of whitespace within it collapse into a single space and
+/// words longer than a line are split. Escape sequences are never split, and the style active at
+/// each break is closed and re-opened so every line stands on its own, after its prefix and
+/// indentation.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+/// use dekor::text::{fill, wrap};
+///
+/// let quote = style!(Italic => "the quick brown fox jumps");
+/// let options = WrapOptions::new(14).prefix("│ ");
+/// assert_eq!(wrap(&quote, options), [
+///     "│ \x1b[3mthe quick\x1b[0m",
+///     "│ \x1b[3mbrown fox\x1b[0m",
+///     "│ \x1b[3mjumps\x1b[0m",
+/// ]);
+///
+/// let bullet = WrapOptions::new(12).initial_indent("- ").subsequent_indent("  ");
+/// assert_eq!(fill("wrapped with a hanging indent", bullet), "- wrapped\n  with a\n  hanging\n  indent");
+///
+/// // Minimum raggedness evens out the lines where greedy filling leaves a short one
+/// assert_eq!(wrap("aaa bb cc ddddd", 6), ["aaa bb", "cc", "ddddd"]);
+/// let even = WrapOptions::new(6).algorithm(WrapAlgorithm::MinRaggedness);
+/// assert_eq!(wrap("aaa bb cc ddddd", even), ["aaa", "bb cc", "ddddd"]);
+/// ```
+///
+pub fn wrap<O: Into<WrapOptions>>(s: &str, options: O) -> Vec<String> {
+    let options = options.into();
+    let mut builder = LineBuilder::new(options.capacities());
+
+    for (i, paragraph) in s.split('\n').enumerate() {
+        if i > 0 {
+            builder.break_line();
+        }
+        let (words, leading) = words(paragraph.trim_end_matches('\r'));
+        leading.into_iter().for_each(|t| builder.push(t));
+        match options.algorithm {
+            WrapAlgorithm::Greedy => greedy(words, &mut builder),
+            WrapAlgorithm::MinRaggedness => min_raggedness(words, &mut builder),
+        }
+    }
+
+    builder
+        .finish()
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let indent = if i == 0 { &options.initial_indent } else { &options.subsequent_indent };
+            format!("{}{}{}", options.prefix, indent, line)
+        })
+        .collect()
+}
+
+/// Wraps `s` like `wrap` and joins the lines with newlines.
+pub fn fill<O: Into<WrapOptions>>(s: &str, options: O) -> String {
+    wrap(s, options).join("\n")
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let s = "\x1b[1;31me\u{301}\x1b]8;;http://a\x07x\x1b[0m";
+        let tokens: Vec<Token> = tokens(s).collect();
+        assert_eq!(tokens, vec![
+            Token::Escape("\x1b[1;31m"),
+            Token::Grapheme("e\u{301}"),
+            Token::Escape("\x1b]8;;http://a\x07"),
+            Token::Grapheme("x"),
+            Token::Escape("\x1b[0m"),
+        ]);
+        assert_eq!(display_width(s), 2);
+    }
+
+    #[test]
+    fn test_grapheme_clusters() {
+        let graphemes = |s| tokens(s).map(|t| match t { Token::Grapheme(g) | Token::Escape(g) => g }).collect::<Vec<_>>();
+        assert_eq!(graphemes("🇺🇸🇫🇷🇩"), vec!["🇺🇸", "🇫🇷", "🇩"]);
+        assert_eq!(graphemes("नमस्ते"), vec!["न", "म", "स्ते"]);
+        assert_eq!(graphemes("สวัสดี"), vec!["ส", "วั", "ส", "ดี"]);
+        assert_eq!(display_width("🇺🇸🇫🇷"), 4);
+        assert_eq!(display_width("नमस्ते"), 3);
+        assert_eq!(display_width("สวัสดี"), 4);
+        assert_eq!(char_width('\u{0E31}'), 0);
+    }
+
+    #[test]
+    fn test_truncate_closes_style() {
+        let s = "\x1b[1mHello, world!\x1b[0m";
+        assert_eq!(truncate(s, 6, "…"), "\x1b[1mHello…\x1b[0m");
+        assert_eq!(truncate("short", 6, "…"), "short");
+        assert_eq!(truncate("日本語", 4, "…"), "日…");
+        let link = "\x1b]8;;https://example.com\x1b\\example\x1b]8;;\x1b\\";
+        assert_eq!(truncate(link, 4, "…"), "\x1b]8;;https://example.com\x1b\\exa…\x1b]8;;\x1b\\");
+    }
+
+    #[test]
+    fn test_wrap_reopens_style() {
+        let s = "\x1b[32mthe quick brown\x1b[0m fox";
+        assert_eq!(wrap(s, 9), vec![
+            "\x1b[32mthe quick\x1b[0m",
+            "\x1b[32mbrown\x1b[0m fox",
+        ]);
+        assert_eq!(wrap("abcdefgh", 3), vec!["abc", "def", "gh"]);
+        assert_eq!(wrap("", 3), vec![""]);
+    }
+
+    #[test]
+    fn test_wrap_style_starting_mid_line() {
+        assert_eq!(wrap("a \x1b[4mb c\x1b[0m", 3), vec!["a \x1b[4mb\x1b[0m", "\x1b[4mc\x1b[0m"]);
+        assert_eq!(wrap("a \x1b[44mb\x1b[0m c", 5), vec!["a \x1b[44mb\x1b[0m c"]);
+    }
+
+    #[test]
+    fn test_wrap_paragraphs_and_long_words() {
+        assert_eq!(wrap("\x1b[1mone\n\ntwo three\x1b[0m", 5), vec![
+            "\x1b[1mone\x1b[0m",
+            "\x1b[1m\x1b[0m",
+            "\x1b[1mtwo\x1b[0m",
+            "\x1b[1mthree\x1b[0m",
+        ]);
+        let options = WrapOptions::new(6).algorithm(WrapAlgorithm::MinRaggedness).initial_indent("> ");
+        assert_eq!(wrap("abcdefghij k", options), vec!["> abcd", "efghij", "k"]);
+        let tiny = WrapOptions::new(2).prefix("│ ");
+        assert_eq!(wrap("ab", tiny), vec!["│ a", "│ b"]);
+    }
+
+    #[test]
+    fn test_pad() {
+        assert_eq!(pad("ab", 5, Align::Left), "ab   ");
+        assert_eq!(pad("ab", 5, Align::Right), "   ab");
+        assert_eq!(pad("ab", 5, Align::Center), " ab  ");
+        assert_eq!(pad("\x1b[1mab\x1b[0m", 3, Align::Left), "\x1b[1mab\x1b[0m ");
+    }
+}
@@ -0,0 +1,198 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::OnceLock;
+
+use crate::style::Paint;
+
+/// Returns whether colored output should be written to `stream`.
+///
+/// Follows the common conventions: a non-empty `NO_COLOR` disables colors, a `CLICOLOR_FORCE`
+/// other than `0` forces them, and `TERM=dumb` disables them; otherwise colors are used when the
+/// stream is a terminal.
+pub fn color_supported<T: IsTerminal>(stream: &T) -> bool {
+    let var = |name: &str| std::env::var(name).unwrap_or_default();
+    if !var("NO_COLOR").is_empty() {
+        return false;
+    }
+    let force = var("CLICOLOR_FORCE");
+    if !force.is_empty() && force != "0" {
+        return true;
+    }
+    var("TERM") != "dumb" && stream.is_terminal()
+}
+
+/// Returns whether colored output should be written to standard output, see `color_supported`.
+///
+/// The result is detected once and cached, which makes it cheap enough to check on every write.
+pub fn stdout_color() -> bool {
+    static COLOR: OnceLock<bool> = OnceLock::new();
+    *COLOR.get_or_init(|| color_supported(&io::stdout()))
+}
+
+/// Returns whether colored output should be written to standard error, see `color_supported`.
+///
+/// The result is detected once and cached, which makes it cheap enough to check on every write.
+pub fn stderr_color() -> bool {
+    static COLOR: OnceLock<bool> = OnceLock::new();
+    *COLOR.get_or_init(|| color_supported(&io::stderr()))
+}
+
+/// A writer adapter that tracks the style of the terminal and only writes the escape codes needed
+/// to go from one style to the next.
+///
+/// Consecutive `write_styled` calls with the same `Paint` write no escape codes at all, and a
+/// change of a single attribute or color writes only that change, e.g. `ESC [ 22 m` to turn bold
+/// off. Nothing is allocated: the codes are assembled on the stack and the text is formatted
+/// straight into the inner writer.
+///
+/// When colors are disabled, text is written without any escape codes. Bytes written through the
+/// `io::Write` implementation keep the current style. The style is reset when the writer is
+/// dropped.
+///
+/// # Examples
+///
+/// ```
+/// use dekor::*;
+///
+/// let mut out = Vec::new();
+/// {
+///     let mut writer = StyledWriter::with_writer(&mut out).color(true);
+///     let label = Paint::new().bold().fg(Color::Green);
+///     writer.write_styled(label, "ok").unwrap();
+///     writer.write_styled(label, " 12").unwrap();
+///     writer.write_styled(Paint::new().fg(Color::Green), " tests\n").unwrap();
+/// }
+/// assert_eq!(String::from_utf8(out).unwrap(), "\x1b[1;32mok 12\x1b[22m tests\n\x1b[0m");
+///
+/// let mut plain = StyledWriter::with_writer(Vec::new());
+/// plain.write_styled(Style::Bold, "no colors").unwrap();
+/// assert_eq!(plain.get_ref(), b"no colors");
+/// ```
+///
+pub struct StyledWriter<W: Write> {
+    writer: W,
+    color: bool,
+    current: Paint,
+}
+
+impl<W: Write + IsTerminal> StyledWriter<W> {
+    /// Wraps a stream, enabling colors when `color_supported` returns `true` for it.
+    pub fn new(writer: W) -> Self {
+        let color = color_supported(&writer);
+        Self::with_writer(writer).color(color)
+    }
+}
+
+impl<W: Write> StyledWriter<W> {
+    /// Wraps any writer, with colors disabled unless `color(true)` is set.
+    pub fn with_writer(writer: W) -> Self {
+        StyledWriter { writer, color: false, current: Paint::new() }
+    }
+
+    /// Sets whether escape codes are written.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Returns `true` if escape codes are written.
+    pub fn is_color(&self) -> bool {
+        self.color
+    }
+
+    /// Writes `text` in `paint`, switching styles only as far as needed.
+    pub fn write_styled<P: Into<Paint>, D: std::fmt::Display>(&mut self, paint: P, text: D) -> io::Result<()> {
+        self.set_paint(paint.into())?;
+        write!(self.writer, "{}", text)
+    }
+
+    /// Switches the style used by subsequent writes.
+    pub fn set_paint(&mut self, paint: Paint) -> io::Result<()> {
+        if !self.color || paint == self.current {
+            return Ok(());
+        }
+        let mut codes = Codes { buf: [0; 64], len: 0 };
+        Paint::write_transition(&self.current, &paint, &mut codes)
+            .map_err(|_| io::Error::new(io::ErrorKind::Other, "escape sequence too long"))?;
+        self.writer.write_all(&codes.buf[..codes.len])?;
+        self.current = paint;
+        Ok(())
+    }
+
+    /// Returns the current style.
+    pub fn paint(&self) -> Paint {
+        self.current
+    }
+
+    /// Resets the style, writing `ESC [ 0 m` if any style is active.
+    pub fn reset(&mut self) -> io::Result<()> {
+        self.set_paint(Paint::new())
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a mutable reference to the inner writer. Escape codes written directly to it are
+    /// not tracked.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+}
+
+impl<W: Write> Write for StyledWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Drop for StyledWriter<W> {
+    fn drop(&mut self) {
+        // Errors cannot be reported from `drop`
+        let _ = self.reset().and_then(|_| self.writer.flush());
+    }
+}
+
+/// A fixed buffer for a single escape sequence, the longest of which is well under 64 bytes.
+struct Codes {
+    buf: [u8; 64],
+    len: usize,
+}
+
+impl std::fmt::Write for Codes {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        self.buf.get_mut(self.len..end).ok_or(std::fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+// ################################################# Tests #################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn test_only_writes_changes() {
+        let mut writer = StyledWriter::with_writer(Vec::new()).color(true);
+        let rgb = Paint::new().fg(Color::Rgb(255, 255, 255)).bg(Color::Rgb(255, 255, 255));
+        writer.write_styled(rgb.bold().italic().underline(), "a").unwrap();
+        writer.write_styled(rgb, "b").unwrap();
+        writer.write_styled(rgb, "c").unwrap();
+        write!(writer, "d").unwrap();
+        writer.reset().unwrap();
+        writer.write_styled(Paint::new(), "e").unwrap();
+        assert_eq!(String::from_utf8(writer.get_ref().clone()).unwrap(), concat!(
+            "\x1b[1;3;4;38;2;255;255;255;48;2;255;255;255ma",
+            "\x1b[22;23;24mbcd",
+            "\x1b[0me",
+        ));
+    }
+}
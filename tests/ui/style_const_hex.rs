@@ -0,0 +1,5 @@
+use dekor::*;
+
+fn main() {
+    let _ = style_const!((FGRGB, "#ff0000") => "x");
+}
@@ -0,0 +1,7 @@
+use dekor::*;
+
+fn main() {
+    let _ = style!((FGRGB) => "text");
+    let _ = paint!((BG256, 1, 2) => "text");
+    let _ = style!((FGOrange, 1) => "text");
+}
@@ -0,0 +1,5 @@
+use dekor::*;
+
+fn main() {
+    let _ = style!(Bold, FGOrange => "text");
+}